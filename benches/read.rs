@@ -6,7 +6,7 @@
 // it under the terms of the MIT License and/or Apache 2.0 License.
 
 use bencher::{benchmark_group, benchmark_main, Bencher};
-use rss::Channel;
+use rss::{Channel, ReadOptions};
 
 fn read_rss2sample(b: &mut Bencher) {
     let input: &[u8] = include_bytes!("../tests/data/rss2sample.xml");
@@ -36,11 +36,44 @@ fn read_syndication(b: &mut Bencher) {
     });
 }
 
+fn large_feed() -> String {
+    let mut input = String::from(r#"<rss version="2.0"><channel><title>Large</title>"#);
+    for i in 0..10_000 {
+        input.push_str(&format!(
+            "<item><title>Item {0}</title><link>http://example.com/{0}</link></item>",
+            i
+        ));
+    }
+    input.push_str("</channel></rss>");
+    input
+}
+
+fn read_large(b: &mut Bencher) {
+    let input = large_feed();
+    b.iter(|| {
+        let _ = Channel::read_from(input.as_bytes()).expect("failed to parse feed");
+    });
+}
+
+fn read_large_expected_items(b: &mut Bencher) {
+    let input = large_feed();
+    let options = ReadOptions {
+        expected_items: 10_000,
+        ..Default::default()
+    };
+    b.iter(|| {
+        let _ = Channel::read_from_with_options(input.as_bytes(), &options)
+            .expect("failed to parse feed");
+    });
+}
+
 benchmark_group!(
     benches,
     read_rss2sample,
     read_itunes,
     read_dublincore,
     read_syndication,
+    read_large,
+    read_large_expected_items,
 );
 benchmark_main!(benches);
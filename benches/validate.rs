@@ -0,0 +1,48 @@
+// This file is part of rss.
+//
+// Copyright © 2015-2021 The rust-syndication Developers
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the MIT License and/or Apache 2.0 License.
+
+use bencher::{benchmark_group, benchmark_main, Bencher};
+use rss::validation::Validate;
+use rss::Channel;
+
+fn large_channel() -> Channel {
+    let mut input = String::from(
+        r#"<rss version="2.0"><channel><title>Large</title><link>http://example.com</link>"#,
+    );
+    for i in 0..10_000 {
+        input.push_str(&format!(
+            "<item><title>Item {0}</title><link>http://example.com/{0}</link>\
+             <pubDate>Sun, 01 Jan 2017 12:00:00 GMT</pubDate>\
+             <enclosure url=\"http://example.com/{0}.mp3\" length=\"1000\" type=\"audio/mpeg\"/>\
+             </item>",
+            i
+        ));
+    }
+    input.push_str("</channel></rss>");
+    Channel::read_from(input.as_bytes()).expect("failed to parse feed")
+}
+
+fn validate_large(b: &mut Bencher) {
+    let channel = large_channel();
+    b.iter(|| {
+        channel.validate().expect("invalid feed");
+    });
+}
+
+#[cfg(feature = "rayon")]
+fn validate_par_large(b: &mut Bencher) {
+    let channel = large_channel();
+    b.iter(|| {
+        channel.validate_par().expect("invalid feed");
+    });
+}
+
+#[cfg(not(feature = "rayon"))]
+benchmark_group!(benches, validate_large);
+#[cfg(feature = "rayon")]
+benchmark_group!(benches, validate_large, validate_par_large);
+benchmark_main!(benches);
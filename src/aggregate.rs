@@ -0,0 +1,142 @@
+// This file is part of rss.
+//
+// Copyright © 2015-2021 The rust-syndication Developers
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the MIT License and/or Apache 2.0 License.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use crate::{Channel, Item};
+
+/// A builder that combines the items of several channels into a single channel.
+///
+/// The items of every channel are merged, sorted newest first by their publication date, and
+/// items whose GUID value was already seen are dropped, keeping the newest copy. Items without
+/// a date that can be parsed are placed after the dated items, in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use rss::aggregate::AggregateBuilder;
+/// use rss::{Channel, Item};
+///
+/// let mut first = Item::default();
+/// first.set_title("First".to_string());
+/// first.set_pub_date("Sun, 01 Jan 2017 12:00:00 GMT".to_string());
+///
+/// let mut second = Item::default();
+/// second.set_title("Second".to_string());
+/// second.set_pub_date("Mon, 02 Jan 2017 12:00:00 GMT".to_string());
+///
+/// let channel = AggregateBuilder::default()
+///     .title("Planet Example")
+///     .link("http://example.com/")
+///     .channel(Channel::from(first))
+///     .channel(Channel::from(second))
+///     .max_items(1)
+///     .build();
+///
+/// assert_eq!(channel.title(), "Planet Example");
+/// assert_eq!(channel.items().len(), 1);
+/// assert_eq!(channel.items()[0].title(), Some("Second"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AggregateBuilder {
+    channels: Vec<Channel>,
+    title: String,
+    link: String,
+    description: String,
+    max_items: Option<usize>,
+}
+
+impl AggregateBuilder {
+    /// Add a channel whose items are aggregated.
+    pub fn channel(&mut self, channel: Channel) -> &mut Self {
+        self.channels.push(channel);
+        self
+    }
+
+    /// Add several channels whose items are aggregated.
+    pub fn channels<I>(&mut self, channels: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Channel>,
+    {
+        self.channels.extend(channels);
+        self
+    }
+
+    /// Set the title of the aggregated channel.
+    pub fn title<V>(&mut self, title: V) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the link of the aggregated channel.
+    pub fn link<V>(&mut self, link: V) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        self.link = link.into();
+        self
+    }
+
+    /// Set the description of the aggregated channel.
+    pub fn description<V>(&mut self, description: V) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        self.description = description.into();
+        self
+    }
+
+    /// Set the maximum number of items in the aggregated channel. The newest items are kept.
+    pub fn max_items(&mut self, max_items: usize) -> &mut Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Builds the aggregated `Channel`.
+    ///
+    /// The namespaces declared by the channels are kept so that the extensions of their items
+    /// are written with the same prefixes. If two channels bind a prefix to different
+    /// namespaces, the first binding is kept.
+    pub fn build(&self) -> Channel {
+        let mut channel = Channel {
+            title: self.title.clone(),
+            link: self.link.clone(),
+            description: self.description.clone(),
+            ..Default::default()
+        };
+
+        let mut items: Vec<Item> = Vec::new();
+        for source in &self.channels {
+            items.extend(source.items().iter().cloned());
+            for (prefix, namespace) in source.namespaces() {
+                channel
+                    .namespaces
+                    .entry(prefix.clone())
+                    .or_insert_with(|| namespace.clone());
+            }
+        }
+
+        items.sort_by_cached_key(|item| Reverse(item.published()));
+
+        let mut guids = HashSet::new();
+        items.retain(|item| match item.guid() {
+            Some(guid) => guids.insert(guid.value().to_string()),
+            None => true,
+        });
+
+        if let Some(max_items) = self.max_items {
+            items.truncate(max_items);
+        }
+
+        channel.items = items;
+        channel
+    }
+}
@@ -32,11 +32,55 @@ use crate::util::{attr_value, decode, element_text};
 pub struct Category {
     /// The name of the category.
     pub name: String,
-    /// The domain for the category.
+    /// The domain for the category. XML entities are unescaped when read and escaped again when
+    /// written; percent-encoded characters are kept as they are.
     pub domain: Option<String>,
 }
 
 impl Category {
+    /// Create a category with the given name and no domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Category;
+    ///
+    /// let category = Category::new("Technology");
+    /// assert_eq!(category.name(), "Technology");
+    /// assert_eq!(category.domain(), None);
+    /// ```
+    pub fn new<V>(name: V) -> Self
+    where
+        V: Into<String>,
+    {
+        Category {
+            name: name.into(),
+            domain: None,
+        }
+    }
+
+    /// Create a category with the given name in the given domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Category;
+    ///
+    /// let category = Category::with_domain("Technology", "http://example.com/categories");
+    /// assert_eq!(category.name(), "Technology");
+    /// assert_eq!(category.domain(), Some("http://example.com/categories"));
+    /// ```
+    pub fn with_domain<N, D>(name: N, domain: D) -> Self
+    where
+        N: Into<String>,
+        D: Into<String>,
+    {
+        Category {
+            name: name.into(),
+            domain: Some(domain.into()),
+        }
+    }
+
     /// Return the name of this category.
     ///
     /// # Examples
@@ -5,38 +5,49 @@
 // This program is free software; you can redistribute it and/or modify
 // it under the terms of the MIT License and/or Apache 2.0 License.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use std::fmt::Display;
 use std::io::{BufRead, Write};
+use std::ops::Range;
 use std::str::{self, FromStr};
 
 use quick_xml::events::attributes::Attributes;
-use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, Event};
+use quick_xml::events::{BytesEnd, BytesPI, BytesStart, Event};
 use quick_xml::Error as XmlError;
 use quick_xml::Reader;
 use quick_xml::Writer;
 
 use crate::category::Category;
 use crate::cloud::Cloud;
+use crate::declaration::XmlDeclaration;
 use crate::error::Error;
 #[cfg(feature = "atom")]
 use crate::extension::atom;
 use crate::extension::dublincore;
 use crate::extension::itunes::{self, is_itunes_namespace};
+use crate::extension::media::{self, is_media_namespace};
 use crate::extension::syndication;
+#[cfg(not(feature = "atom"))]
+use crate::extension::util::atom_namespace_declaration;
 use crate::extension::util::{
-    extension_entry, extension_name, parse_extension_element, read_namespace_declarations,
+    core_element_name, dedup_namespaces, extension_entry, extension_name, parse_extension_element,
+    read_namespace_declarations, sort_extensions, ATOM_NAMESPACE,
 };
-use crate::extension::ExtensionMap;
+use crate::extension::{Extension, ExtensionMap};
 use crate::image::Image;
 use crate::item::Item;
+use crate::lint::{self, Lint};
+use crate::options::{Escape, Indent, ItemOrder, NamespacePlacement, ReadOptions, WriteOptions};
 use crate::textinput::TextInput;
-use crate::toxml::{ToXml, WriterExt};
-use crate::util::{decode, element_text, skip};
+use crate::toxml::{rewrite_document, ToXml, WriterExt};
+use crate::util::{
+    attr_value, declare_utf8, decode, element_text_with, normalize_categories, normalize_date,
+    read_utf16, skip, trim_in_place, xml_lang, Recorder,
+};
 
 /// Represents the channel of an RSS feed.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(feature = "builders", derive(Builder))]
 #[cfg_attr(
     feature = "builders",
@@ -55,6 +66,8 @@ pub struct Channel {
     pub description: String,
     /// The language of the channel.
     pub language: Option<String>,
+    /// The `xml:lang` attribute of the channel element.
+    pub xml_lang: Option<String>,
     /// The copyright notice for the channel.
     pub copyright: Option<String>,
     /// The email address for the managing editor.
@@ -106,9 +119,47 @@ pub struct Channel {
     /// The namespaces present in the RSS tag.
     #[cfg_attr(feature = "builders", builder(setter(each = "namespace")))]
     pub namespaces: BTreeMap<String, String>,
+    /// The XML declaration of the document the channel was read from. `None` if the document
+    /// had no declaration.
+    ///
+    /// A declaration is always written, so this is not compared by `PartialEq`.
+    pub xml_declaration: Option<XmlDeclaration>,
+    /// The format of the document the channel was read from.
+    ///
+    /// This describes the source document rather than the channel, so it is not compared by
+    /// `PartialEq`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source_format: FeedFormat,
+    /// The processing instructions before the `<rss>` element, such as
+    /// `xml-stylesheet href="style.xsl" type="text/xsl"`, without the `<?` and `?>`. An
+    /// instruction that contains `?>` cannot be written and makes writing fail.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "builders", builder(setter(each = "processing_instruction")))]
+    pub processing_instructions: Vec<String>,
 }
 
 impl Channel {
+    /// The URL of the RSS 2.0 specification, a canonical value for `docs`.
+    pub const DEFAULT_DOCS: &'static str = "https://www.rssboard.org/rss-specification";
+
+    /// Create an empty channel with space reserved for at least `capacity` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let channel = Channel::with_item_capacity(100);
+    /// assert!(channel.items().is_empty());
+    /// assert!(channel.items.capacity() >= 100);
+    /// ```
+    pub fn with_item_capacity(capacity: usize) -> Channel {
+        Channel {
+            items: Vec::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+
     /// Return the title of this channel.
     ///
     /// # Examples
@@ -216,8 +267,18 @@ impl Channel {
     /// channel.set_language("en-US".to_string());
     /// assert_eq!(channel.language(), Some("en-US"));
     /// ```
+    ///
+    /// The `xml:lang` attribute is used when the channel has no `<language>` element.
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_xml_lang("fr".to_string());
+    /// assert_eq!(channel.language(), Some("fr"));
+    /// ```
     pub fn language(&self) -> Option<&str> {
-        self.language.as_ref().map(String::as_ref)
+        self.language.as_deref().or(self.xml_lang.as_deref())
     }
 
     /// Set the language of this channel.
@@ -237,6 +298,38 @@ impl Channel {
         self.language = language.into();
     }
 
+    /// Return the `xml:lang` attribute of this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_xml_lang("fr".to_string());
+    /// assert_eq!(channel.xml_lang(), Some("fr"));
+    /// ```
+    pub fn xml_lang(&self) -> Option<&str> {
+        self.xml_lang.as_deref()
+    }
+
+    /// Set the `xml:lang` attribute of this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_xml_lang("fr".to_string());
+    /// ```
+    pub fn set_xml_lang<V>(&mut self, xml_lang: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.xml_lang = xml_lang.into();
+    }
+
     /// Return the copyright notice for this channel.
     ///
     /// # Examples
@@ -360,24 +453,55 @@ impl Channel {
     /// channel.set_pub_date("Sun, 1 Jan 2017 12:00:00 GMT".to_string());
     /// assert_eq!(channel.pub_date(), Some("Sun, 1 Jan 2017 12:00:00 GMT"));
     /// ```
+    pub fn set_pub_date<V>(&mut self, pub_date: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.pub_date = pub_date.into();
+    }
+
+    /// Set the publication date of this channel from a `DateTime`, formatted as an RFC822
+    /// timestamp.
+    ///
+    /// # Examples
     ///
-    /// ## Using chrono::DateTime
     /// ```
-    /// # #[cfg(feature = "validation")]
-    /// # {
     /// use rss::Channel;
     /// use chrono::{TimeZone, Utc};
     ///
     /// let mut channel = Channel::default();
-    /// channel.set_pub_date(Utc.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap().to_rfc2822());
+    /// channel.set_pub_datetime(Utc.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap());
     /// assert_eq!(channel.pub_date(), Some("Sun, 1 Jan 2017 12:00:00 +0000"));
-    /// # }
     /// ```
-    pub fn set_pub_date<V>(&mut self, pub_date: V)
+    #[cfg(feature = "validation")]
+    pub fn set_pub_datetime<Tz>(&mut self, pub_date: chrono::DateTime<Tz>)
     where
-        V: Into<Option<String>>,
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
     {
-        self.pub_date = pub_date.into();
+        self.pub_date = Some(pub_date.to_rfc2822());
+    }
+
+    /// Parse the publication date of this channel, accepting the named time zones listed in
+    /// [`parse_rfc822_date`](crate::validation::parse_rfc822_date).
+    ///
+    /// Returns `None` if the channel has no publication date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_pub_date("Sun, 01 Jan 2017 12:00:00 EST".to_string());
+    /// let date = channel.pub_date_parsed().unwrap().unwrap();
+    /// assert_eq!(date.to_rfc3339(), "2017-01-01T12:00:00-05:00");
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn pub_date_parsed(
+        &self,
+    ) -> Option<Result<chrono::DateTime<chrono::FixedOffset>, chrono::ParseError>> {
+        self.pub_date().map(crate::validation::parse_rfc822_date)
     }
 
     /// Return the time that the content of this channel was last changed as an RFC822 timestamp.
@@ -406,24 +530,94 @@ impl Channel {
     /// channel.set_last_build_date("Sun, 1 Jan 2017 12:00:00 GMT".to_string());
     /// assert_eq!(channel.last_build_date(), Some("Sun, 1 Jan 2017 12:00:00 GMT"));
     /// ```
+    pub fn set_last_build_date<V>(&mut self, last_build_date: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.last_build_date = last_build_date.into();
+    }
+
+    /// Set the time that the content of this channel was last changed from a `DateTime`,
+    /// formatted as an RFC822 timestamp.
+    ///
+    /// # Examples
     ///
-    /// ## Using chrono::DateTime
     /// ```
-    /// # #[cfg(feature = "validation")]
-    /// # {
     /// use rss::Channel;
     /// use chrono::{TimeZone, Utc};
     ///
     /// let mut channel = Channel::default();
-    /// channel.set_last_build_date(Utc.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap().to_rfc2822());
+    /// channel.set_last_build_datetime(Utc.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap());
     /// assert_eq!(channel.last_build_date(), Some("Sun, 1 Jan 2017 12:00:00 +0000"));
-    /// # }
     /// ```
-    pub fn set_last_build_date<V>(&mut self, last_build_date: V)
+    #[cfg(feature = "validation")]
+    pub fn set_last_build_datetime<Tz>(&mut self, last_build_date: chrono::DateTime<Tz>)
     where
-        V: Into<Option<String>>,
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
     {
-        self.last_build_date = last_build_date.into();
+        self.last_build_date = Some(last_build_date.to_rfc2822());
+    }
+
+    /// Parse the last build date of this channel, accepting the named time zones listed in
+    /// [`parse_rfc822_date`](crate::validation::parse_rfc822_date).
+    ///
+    /// Returns `None` if the channel has no last build date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_last_build_date("Sun, 01 Jan 2017 12:00:00 EST".to_string());
+    /// let date = channel.last_build_date_parsed().unwrap().unwrap();
+    /// assert_eq!(date.to_rfc3339(), "2017-01-01T12:00:00-05:00");
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn last_build_date_parsed(
+        &self,
+    ) -> Option<Result<chrono::DateTime<chrono::FixedOffset>, chrono::ParseError>> {
+        self.last_build_date()
+            .map(crate::validation::parse_rfc822_date)
+    }
+
+    /// Return how long ago this channel was last changed.
+    ///
+    /// The age is measured from the last build date, falling back to the publication date. Both
+    /// RFC 822 and RFC 3339 dates are accepted. Returns `None` if neither date can be parsed.
+    ///
+    /// See [`age_at`](Channel::age_at) to measure the age against a fixed time.
+    #[cfg(feature = "validation")]
+    pub fn age(&self) -> Option<chrono::Duration> {
+        self.age_at(chrono::DateTime::from(std::time::SystemTime::now()))
+    }
+
+    /// Return how long before `now` this channel was last changed.
+    ///
+    /// The age is measured from the last build date, falling back to the publication date. Both
+    /// RFC 822 and RFC 3339 dates are accepted. Returns `None` if neither date can be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_pub_date("Sun, 01 Jan 2017 12:00:00 GMT".to_string());
+    ///
+    /// let now = Utc.with_ymd_and_hms(2017, 1, 1, 18, 0, 0).unwrap();
+    /// assert_eq!(channel.age_at(now), Some(Duration::hours(6)));
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn age_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<chrono::Duration> {
+        use crate::date::parse_date;
+
+        self.last_build_date()
+            .and_then(parse_date)
+            .or_else(|| self.pub_date().and_then(parse_date))
+            .map(|date| now.signed_duration_since(date))
     }
 
     /// Return the categories that this channel belongs to.
@@ -463,6 +657,32 @@ impl Channel {
         self.categories = categories.into();
     }
 
+    /// Return the names of the categories of all items in this channel, trimmed and lowercased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Category, Channel, Item};
+    ///
+    /// let mut first = Item::default();
+    /// first.set_categories(vec![Category::from("Rust")]);
+    /// let mut second = Item::default();
+    /// second.set_categories(vec![Category::from("rust"), Category::from("XML")]);
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_items(vec![first, second]);
+    ///
+    /// let names = channel.collect_category_names();
+    /// assert_eq!(names.into_iter().collect::<Vec<_>>(), ["rust", "xml"]);
+    /// ```
+    pub fn collect_category_names(&self) -> BTreeSet<String> {
+        self.items
+            .iter()
+            .flat_map(|item| item.categories())
+            .map(|category| category.name().trim().to_lowercase())
+            .collect()
+    }
+
     /// Return a string indicating the program used to generate the channel.
     ///
     /// # Examples
@@ -527,6 +747,43 @@ impl Channel {
         self.docs = docs.into();
     }
 
+    /// Parse the documentation URL of this channel.
+    ///
+    /// Returns `None` if the channel has no documentation URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_docs("https://cyber.harvard.edu/rss/rss.html".to_string());
+    /// let url = channel.docs_url().unwrap().unwrap();
+    /// assert_eq!(url.host_str(), Some("cyber.harvard.edu"));
+    ///
+    /// channel.set_docs("not a url".to_string());
+    /// assert!(channel.docs_url().unwrap().is_err());
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn docs_url(&self) -> Option<Result<url::Url, url::ParseError>> {
+        self.docs().map(url::Url::parse)
+    }
+
+    /// Set the documentation URL of this channel to `Channel::DEFAULT_DOCS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_default_docs();
+    /// assert_eq!(channel.docs(), Some(Channel::DEFAULT_DOCS));
+    /// ```
+    pub fn set_default_docs(&mut self) {
+        self.docs = Some(Channel::DEFAULT_DOCS.to_string());
+    }
+
     /// Return the information used to register with a cloud for notifications of updates to the
     /// channel.
     ///
@@ -627,6 +884,147 @@ impl Channel {
         self.image = image.into();
     }
 
+    /// Return the URL of the best artwork for this channel.
+    ///
+    /// The iTunes image is preferred, followed by the URL of the `<image>` element and finally
+    /// the `href` of an Atom link with `rel="icon"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, Image};
+    /// use rss::extension::itunes::ITunesChannelExtension;
+    ///
+    /// let mut channel = Channel::default();
+    /// assert_eq!(channel.artwork_url(), None);
+    ///
+    /// let mut image = Image::default();
+    /// image.set_url("http://example.com/image.png");
+    /// channel.set_image(image);
+    /// assert_eq!(channel.artwork_url(), Some("http://example.com/image.png"));
+    ///
+    /// let mut itunes = ITunesChannelExtension::default();
+    /// itunes.set_image("http://example.com/artwork.jpg".to_string());
+    /// channel.set_itunes_ext(itunes);
+    /// assert_eq!(channel.artwork_url(), Some("http://example.com/artwork.jpg"));
+    /// ```
+    pub fn artwork_url(&self) -> Option<&str> {
+        if let Some(url) = self.itunes_ext().and_then(|ext| ext.image()) {
+            return Some(url);
+        }
+        if let Some(image) = self.image().filter(|image| !image.url().is_empty()) {
+            return Some(image.url());
+        }
+        #[cfg(feature = "atom")]
+        if let Some(link) = self
+            .atom_ext()
+            .and_then(|ext| ext.links().iter().find(|link| link.rel() == "icon"))
+        {
+            return Some(link.href());
+        }
+        None
+    }
+
+    /// Return the URL that this feed has permanently moved to, from `itunes:new-feed-url`.
+    ///
+    /// Clients should update their subscription to the new URL and fetch the feed from there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesChannelExtension;
+    /// use rss::Channel;
+    ///
+    /// let mut itunes = ITunesChannelExtension::default();
+    /// itunes.set_new_feed_url("http://example.com/new-feed.xml".to_string());
+    ///
+    /// let mut channel = Channel::default();
+    /// assert_eq!(channel.moved_to(), None);
+    /// channel.set_itunes_ext(itunes);
+    /// assert_eq!(channel.moved_to(), Some("http://example.com/new-feed.xml"));
+    /// ```
+    pub fn moved_to(&self) -> Option<&str> {
+        self.itunes_ext().and_then(|ext| ext.new_feed_url())
+    }
+
+    /// Return the URL of the artwork whose size is closest to `target_px` without being smaller.
+    ///
+    /// The candidates are the channel's `media:thumbnail` elements, the iTunes image and the
+    /// `<image>` element. The size of a candidate is the larger of its width and height. The
+    /// iTunes image is assumed to be 1400 pixels, the minimum Apple accepts, and an `<image>`
+    /// without a width is assumed to be 88 pixels, the default of the RSS specification.
+    /// Thumbnails without dimensions are ignored.
+    ///
+    /// The smallest candidate of at least `target_px` is returned. If every candidate is
+    /// smaller, the largest one is returned instead. Ties go to the candidate listed first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = r#"
+    /// <rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+    ///     <channel>
+    ///         <media:thumbnail url="http://example.com/small.jpg" width="100" height="100"/>
+    ///         <media:thumbnail url="http://example.com/large.jpg" width="600" height="600"/>
+    ///     </channel>
+    /// </rss>
+    /// "#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    /// assert_eq!(channel.best_artwork(300), Some("http://example.com/large.jpg"));
+    /// ```
+    pub fn best_artwork(&self, target_px: u32) -> Option<&str> {
+        let thumbnails = self
+            .extensions
+            .iter()
+            .filter(|(prefix, _)| {
+                self.namespaces
+                    .get(prefix.as_str())
+                    .map_or(prefix.as_str() == "media", |ns| is_media_namespace(ns))
+            })
+            .filter_map(|(_, map)| map.get("thumbnail"))
+            .flatten()
+            .filter_map(|ext| {
+                let url = ext.attrs.get("url")?;
+                let thumbnail = media::MediaThumbnail::from_extension(ext.clone());
+                let size = thumbnail.width().max(thumbnail.height())?;
+                Some((url.as_str(), size))
+            });
+        let itunes = self
+            .itunes_ext()
+            .and_then(|ext| ext.image())
+            .map(|url| (url, 1400));
+        let image = self
+            .image()
+            .filter(|image| !image.url().is_empty())
+            .map(|image| {
+                let width = image.width().and_then(|v| v.trim().parse::<u32>().ok());
+                let height = image.height().and_then(|v| v.trim().parse::<u32>().ok());
+                (image.url(), width.unwrap_or(88).max(height.unwrap_or(0)))
+            });
+
+        let mut best: Option<(&str, u32)> = None;
+        for (url, size) in thumbnails.chain(itunes).chain(image) {
+            best = match best {
+                None => Some((url, size)),
+                Some((_, best_size)) => {
+                    let better = if best_size >= target_px {
+                        size >= target_px && size < best_size
+                    } else {
+                        size > best_size
+                    };
+                    if better {
+                        Some((url, size))
+                    } else {
+                        best
+                    }
+                }
+            };
+        }
+        best.map(|(url, _)| url)
+    }
+
     /// Return the [PICS](https://www.w3.org/PICS/) rating for this channel.
     pub fn rating(&self) -> Option<&str> {
         self.rating.as_deref()
@@ -800,83 +1198,381 @@ impl Channel {
         self.items = items.into();
     }
 
-    /// Return the Atom extension for this channel.
+    /// Consume the `Channel` and return it with its items replaced, keeping the rest of the
+    /// channel.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rss::Channel;
-    /// use rss::extension::atom::AtomExtension;
+    /// use rss::{Channel, Item};
     ///
     /// let mut channel = Channel::default();
-    /// channel.set_atom_ext(AtomExtension::default());
-    /// assert!(channel.atom_ext().is_some());
+    /// channel.set_title("Feed");
+    /// let channel = channel.with_items(vec![Item::default()]);
+    /// assert_eq!(channel.title(), "Feed");
+    /// assert_eq!(channel.items().len(), 1);
     /// ```
-    #[cfg(feature = "atom")]
-    pub fn atom_ext(&self) -> Option<&atom::AtomExtension> {
-        self.atom_ext.as_ref()
+    pub fn with_items<V>(mut self, items: V) -> Channel
+    where
+        V: Into<Vec<Item>>,
+    {
+        self.set_items(items);
+        self
     }
 
-    /// Set the Atom extension for this channel.
+    /// Remove all items from this channel.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rss::Channel;
-    /// use rss::extension::atom::AtomExtension;
+    /// use rss::{Channel, Item};
     ///
     /// let mut channel = Channel::default();
-    /// channel.set_atom_ext(AtomExtension::default());
+    /// channel.set_items(vec![Item::default()]);
+    /// channel.clear_items();
+    /// assert!(channel.items().is_empty());
     /// ```
-    #[cfg(feature = "atom")]
-    pub fn set_atom_ext<V>(&mut self, atom_ext: V)
-    where
-        V: Into<Option<atom::AtomExtension>>,
-    {
-        self.atom_ext = atom_ext.into();
+    pub fn clear_items(&mut self) {
+        self.items.clear();
     }
 
-    /// Return the iTunes extension for this channel.
+    /// Sort the items of this channel by their numeric `itunes:order`.
+    ///
+    /// Items without an `itunes:order`, or with one that is not a number, are placed after the
+    /// ordered items and keep their relative positions.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rss::Channel;
-    /// use rss::extension::itunes::ITunesChannelExtension;
+    /// use rss::extension::itunes::ITunesItemExtension;
+    /// use rss::{Channel, Item};
+    ///
+    /// let item = |order: &str| {
+    ///     let mut itunes = ITunesItemExtension::default();
+    ///     itunes.set_order(order.to_string());
+    ///     let mut item = Item::default();
+    ///     item.set_itunes_ext(itunes);
+    ///     item
+    /// };
     ///
     /// let mut channel = Channel::default();
-    /// channel.set_itunes_ext(ITunesChannelExtension::default());
-    /// assert!(channel.itunes_ext().is_some());
+    /// channel.set_items(vec![item("2"), Item::default(), item("1")]);
+    /// channel.sort_items_by_itunes_order();
+    /// assert_eq!(channel.items()[0].itunes_ext().unwrap().order(), Some("1"));
+    /// assert_eq!(channel.items()[1].itunes_ext().unwrap().order(), Some("2"));
+    /// assert!(channel.items()[2].itunes_ext().is_none());
+    /// ```
+    pub fn sort_items_by_itunes_order(&mut self) {
+        self.items.sort_by_key(|item| {
+            let order = item
+                .itunes_ext()
+                .and_then(|ext| ext.order_num())
+                .and_then(Result::ok);
+            (order.is_none(), order)
+        });
+    }
+
+    /// Bring this channel into a canonical form so that equivalent feeds, such as copies from
+    /// different mirrors, compare equal.
+    ///
+    /// The title, link, description, language, copyright, managing editor, webmaster, dates,
+    /// generator, docs, rating and TTL are trimmed, as are the fields of the image, text input
+    /// and cloud. The publication and last build dates are written as RFC 2822 if they can be
+    /// parsed, which requires the `validation` feature. Categories are trimmed and sorted by
+    /// domain and name. Prefixes bound to the same namespace are merged into the first of them
+    /// in alphabetical order, in the channel and its items, and the elements of each extension
+    /// are sorted. Every item is normalized with [`Item::normalize`]. The order of the items is
+    /// kept. Nothing but surrounding whitespace is removed.
+    ///
+    /// # Examples
+    ///
     /// ```
-    pub fn itunes_ext(&self) -> Option<&itunes::ITunesChannelExtension> {
-        self.itunes_ext.as_ref()
+    /// use rss::Channel;
+    ///
+    /// let mut first: Channel = r#"<rss version="2.0"><channel>
+    ///     <title> Title </title><category>b</category><category>a</category>
+    /// </channel></rss>"#
+    ///     .parse()
+    ///     .unwrap();
+    /// let mut second: Channel = r#"<rss version="2.0"><channel>
+    ///     <title>Title</title><category>a</category><category>b</category>
+    /// </channel></rss>"#
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_ne!(first, second);
+    ///
+    /// first.normalize();
+    /// second.normalize();
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn normalize(&mut self) {
+        for text in [&mut self.title, &mut self.link, &mut self.description] {
+            trim_in_place(text);
+        }
+        for text in [
+            &mut self.language,
+            &mut self.copyright,
+            &mut self.managing_editor,
+            &mut self.webmaster,
+            &mut self.generator,
+            &mut self.docs,
+            &mut self.rating,
+            &mut self.ttl,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            trim_in_place(text);
+        }
+        if let Some(date) = self.pub_date.as_mut() {
+            normalize_date(date);
+        }
+        if let Some(date) = self.last_build_date.as_mut() {
+            normalize_date(date);
+        }
+        if let Some(image) = self.image.as_mut() {
+            for text in [&mut image.url, &mut image.title, &mut image.link] {
+                trim_in_place(text);
+            }
+            for text in [&mut image.width, &mut image.height, &mut image.description]
+                .into_iter()
+                .flatten()
+            {
+                trim_in_place(text);
+            }
+        }
+        if let Some(text_input) = self.text_input.as_mut() {
+            for text in [
+                &mut text_input.title,
+                &mut text_input.description,
+                &mut text_input.name,
+                &mut text_input.link,
+            ] {
+                trim_in_place(text);
+            }
+        }
+        if let Some(cloud) = self.cloud.as_mut() {
+            for text in [
+                &mut cloud.domain,
+                &mut cloud.port,
+                &mut cloud.path,
+                &mut cloud.register_procedure,
+                &mut cloud.protocol,
+            ] {
+                trim_in_place(text);
+            }
+        }
+        normalize_categories(&mut self.categories);
+        let renames = dedup_namespaces(&mut self.namespaces, &mut self.extensions);
+        sort_extensions(&mut self.extensions);
+        for item in &mut self.items {
+            for (from, to) in &renames {
+                item.rename_prefix(from, to, &self.namespaces[to]);
+            }
+            item.normalize();
+        }
     }
 
-    /// Set the iTunes extension for this channel.
+    /// Return advisory findings about this channel.
+    ///
+    /// Unlike validation errors, lints describe deviations from best practice that readers
+    /// usually tolerate.
     ///
     /// # Examples
     ///
     /// ```
-    /// use rss::Channel;
-    /// use rss::extension::itunes::ITunesChannelExtension;
+    /// use rss::{Channel, Item, Lint};
     ///
     /// let mut channel = Channel::default();
-    /// channel.set_itunes_ext(ITunesChannelExtension::default());
+    /// channel.set_items(vec![Item::default()]);
+    ///
+    /// let lints = channel.lint();
+    /// assert!(lints.contains(&Lint::ItemWithoutTitleOrDescription(0)));
+    /// assert_eq!(
+    ///     Lint::ItemWithoutTitleOrDescription(0).to_string(),
+    ///     "item 0 has neither title nor description (RSS requires one)"
+    /// );
     /// ```
-    pub fn set_itunes_ext<V>(&mut self, itunes_ext: V)
-    where
-        V: Into<Option<itunes::ITunesChannelExtension>>,
-    {
-        self.itunes_ext = itunes_ext.into();
+    pub fn lint(&self) -> Vec<Lint> {
+        lint::lint(self)
     }
 
-    /// Return the Dublin Core extension for this channel.
+    /// Return the Atom extension for this channel.
     ///
     /// # Examples
     ///
     /// ```
     /// use rss::Channel;
-    /// use rss::extension::dublincore::DublinCoreExtension;
+    /// use rss::extension::atom::AtomExtension;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_atom_ext(AtomExtension::default());
+    /// assert!(channel.atom_ext().is_some());
+    /// ```
+    #[cfg(feature = "atom")]
+    pub fn atom_ext(&self) -> Option<&atom::AtomExtension> {
+        self.atom_ext.as_ref()
+    }
+
+    /// Set the Atom extension for this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    /// use rss::extension::atom::AtomExtension;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_atom_ext(AtomExtension::default());
+    /// ```
+    #[cfg(feature = "atom")]
+    pub fn set_atom_ext<V>(&mut self, atom_ext: V)
+    where
+        V: Into<Option<atom::AtomExtension>>,
+    {
+        self.atom_ext = atom_ext.into();
+    }
+
+    /// Return the `href` and `rel` of the `atom:link` elements of this channel.
+    ///
+    /// This works with or without the `atom` feature. Without it, the links are read from the
+    /// generic extensions under the prefix bound to the Atom namespace, or `atom` if the
+    /// namespace is not declared. A link without `rel` has the Atom default, `alternate`, and a
+    /// link without `href` is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = r#"
+    /// <rss version="2.0" xmlns:a="http://www.w3.org/2005/Atom">
+    ///     <channel>
+    ///         <a:link rel="self" href="http://example.com/feed.xml"/>
+    ///     </channel>
+    /// </rss>
+    /// "#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    /// assert_eq!(channel.atom_links_raw(), [("http://example.com/feed.xml", "self")]);
+    /// ```
+    pub fn atom_links_raw(&self) -> Vec<(&str, &str)> {
+        let mut links = Vec::new();
+
+        #[cfg(feature = "atom")]
+        if let Some(ext) = self.atom_ext() {
+            links.extend(ext.links().iter().map(|link| (link.href(), link.rel())));
+        }
+
+        let prefix = self.has_namespace(ATOM_NAMESPACE).unwrap_or("atom");
+        let generic = self
+            .extensions
+            .get(prefix)
+            .and_then(|map| map.get("link"))
+            .into_iter()
+            .flatten()
+            .filter_map(|link| {
+                let href = link.attrs().get("href")?;
+                let rel = link.attrs().get("rel").map_or("alternate", String::as_str);
+                Some((href.as_str(), rel))
+            });
+        links.extend(generic);
+        links
+    }
+
+    /// Return the iTunes extension for this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    /// use rss::extension::itunes::ITunesChannelExtension;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_itunes_ext(ITunesChannelExtension::default());
+    /// assert!(channel.itunes_ext().is_some());
+    /// ```
+    pub fn itunes_ext(&self) -> Option<&itunes::ITunesChannelExtension> {
+        self.itunes_ext.as_ref()
+    }
+
+    /// Set the iTunes extension for this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    /// use rss::extension::itunes::ITunesChannelExtension;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_itunes_ext(ITunesChannelExtension::default());
+    /// ```
+    pub fn set_itunes_ext<V>(&mut self, itunes_ext: V)
+    where
+        V: Into<Option<itunes::ITunesChannelExtension>>,
+    {
+        self.itunes_ext = itunes_ext.into();
+    }
+
+    /// Return whether this channel is a podcast.
+    ///
+    /// A channel is a podcast if it has an iTunes extension or any of its items has an audio or
+    /// video enclosure. An enclosure without a MIME type is judged by the extension of its URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, Enclosure, Item};
+    ///
+    /// let mut channel = Channel::default();
+    /// assert!(!channel.is_podcast());
+    ///
+    /// let mut enclosure = Enclosure::default();
+    /// enclosure.set_url("http://example.com/episode.mp3");
+    /// let mut item = Item::default();
+    /// item.set_enclosure(enclosure);
+    /// channel.set_items(vec![item]);
+    /// assert!(channel.is_podcast());
+    /// ```
+    pub fn is_podcast(&self) -> bool {
+        self.itunes_ext.is_some()
+            || self
+                .items
+                .iter()
+                .filter_map(Item::enclosure)
+                .any(|enclosure| {
+                    let mime_type = enclosure
+                        .inferred_mime_type()
+                        .unwrap_or_else(|| enclosure.mime_type().trim());
+                    let mime_type = mime_type.to_ascii_lowercase();
+                    mime_type.starts_with("audio/") || mime_type.starts_with("video/")
+                })
+    }
+
+    /// Return the `itunes:type` of this podcast, `episodic` or `serial`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    /// use rss::extension::itunes::ITunesChannelExtension;
+    ///
+    /// let mut itunes = ITunesChannelExtension::default();
+    /// itunes.set_type("serial".to_string());
+    /// let mut channel = Channel::default();
+    /// channel.set_itunes_ext(itunes);
+    /// assert_eq!(channel.podcast_kind(), Some("serial"));
+    /// ```
+    pub fn podcast_kind(&self) -> Option<&str> {
+        self.itunes_ext.as_ref().and_then(|ext| ext.r#type())
+    }
+
+    /// Return the Dublin Core extension for this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    /// use rss::extension::dublincore::DublinCoreExtension;
     ///
     /// let mut channel = Channel::default();
     /// channel.set_dublin_core_ext(DublinCoreExtension::default());
@@ -985,162 +1681,907 @@ impl Channel {
         self.extensions = extensions.into()
     }
 
-    /// Return the namespaces for this channel.
+    /// Return an iterator over the extensions of this channel and of its items, as
+    /// `(prefix, name, extension)`.
+    ///
+    /// The extensions of the channel come first, followed by those of each item in order.
+    /// Only the top-level extension elements are yielded, not their children. Extensions that
+    /// are parsed into typed fields, such as `itunes_ext`, are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = r#"<rss xmlns:ext="http://example.com/"><channel><ext:a/><item><ext:b/></item></channel></rss>"#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    ///
+    /// let names = channel
+    ///     .all_extensions()
+    ///     .map(|(prefix, name, _)| format!("{}:{}", prefix, name))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, ["ext:a", "ext:b"]);
+    /// ```
+    pub fn all_extensions(&self) -> impl Iterator<Item = (&str, &str, &Extension)> {
+        std::iter::once(&self.extensions)
+            .chain(self.items.iter().map(|item| item.extensions()))
+            .flat_map(|extensions| {
+                extensions.iter().flat_map(|(prefix, map)| {
+                    map.iter().flat_map(move |(name, extensions)| {
+                        extensions
+                            .iter()
+                            .map(move |extension| (prefix.as_str(), name.as_str(), extension))
+                    })
+                })
+            })
+    }
+
+    /// Return the namespaces for this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use rss::Channel;
+    ///
+    /// let mut namespaces = BTreeMap::new();
+    /// namespaces.insert("ext".to_string(), "http://example.com".to_string());
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_namespaces(namespaces);
+    /// assert_eq!(channel.namespaces().get("ext").map(String::as_str), Some("http://example.com"));
+    /// ```
+    pub fn namespaces(&self) -> &BTreeMap<String, String> {
+        &self.namespaces
+    }
+
+    /// Set the namespaces for this channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_namespaces(BTreeMap::new());
+    /// ```
+    pub fn set_namespaces<V>(&mut self, namespaces: V)
+    where
+        V: Into<BTreeMap<String, String>>,
+    {
+        self.namespaces = namespaces.into()
+    }
+
+    /// Return the XML declaration of the document this channel was read from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = r#"<?xml version="1.0" encoding="UTF-8"?><rss><channel></channel></rss>"#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    /// assert_eq!(channel.xml_declaration().unwrap().encoding(), Some("UTF-8"));
+    /// ```
+    pub fn xml_declaration(&self) -> Option<&XmlDeclaration> {
+        self.xml_declaration.as_ref()
+    }
+
+    /// Set the XML declaration for this channel. The version, `standalone` value and, if it
+    /// names UTF-8, the encoding are used when the channel is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, XmlDeclaration};
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_xml_declaration(XmlDeclaration::default());
+    /// ```
+    pub fn set_xml_declaration<V>(&mut self, xml_declaration: V)
+    where
+        V: Into<Option<XmlDeclaration>>,
+    {
+        self.xml_declaration = xml_declaration.into();
+    }
+
+    /// Return the processing instructions before the `<rss>` element.
+    ///
+    /// They are only read with `ReadOptions::preserve_processing_instructions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, ReadOptions};
+    ///
+    /// let input = r#"<?xml-stylesheet href="style.xsl" type="text/xsl"?><rss><channel></channel></rss>"#;
+    /// let options = ReadOptions {
+    ///     preserve_processing_instructions: true,
+    ///     ..Default::default()
+    /// };
+    /// let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    /// assert_eq!(
+    ///     channel.processing_instructions(),
+    ///     [r#"xml-stylesheet href="style.xsl" type="text/xsl""#]
+    /// );
+    /// ```
+    pub fn processing_instructions(&self) -> &[String] {
+        &self.processing_instructions
+    }
+
+    /// Set the processing instructions written before the `<rss>` element, without the `<?`
+    /// and `?>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_processing_instructions(vec![
+    ///     r#"xml-stylesheet href="style.xsl" type="text/xsl""#.to_string(),
+    /// ]);
+    /// ```
+    pub fn set_processing_instructions<V>(&mut self, processing_instructions: V)
+    where
+        V: Into<Vec<String>>,
+    {
+        self.processing_instructions = processing_instructions.into();
+    }
+
+    /// Return the format of the document this channel was read from. Channels that were not
+    /// read from a document report RSS 2.0, the format they are written in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, FeedFormat};
+    ///
+    /// let input = r#"<rss version="0.91"><channel></channel></rss>"#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    /// assert_eq!(channel.feed_type(), FeedFormat::Rss09);
+    /// ```
+    pub fn feed_type(&self) -> FeedFormat {
+        self.source_format
+    }
+
+    /// Bind a namespace prefix to a URI for this channel.
+    ///
+    /// An existing binding for the same prefix is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.register_namespace("ext", "http://example.com/ext");
+    /// assert_eq!(channel.namespaces().get("ext").map(String::as_str), Some("http://example.com/ext"));
+    /// ```
+    pub fn register_namespace<P, U>(&mut self, prefix: P, uri: U)
+    where
+        P: Into<String>,
+        U: Into<String>,
+    {
+        self.namespaces.insert(prefix.into(), uri.into());
+    }
+
+    /// Return the prefix bound to a namespace URI in this channel, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.register_namespace("ext", "http://example.com/ext");
+    /// assert_eq!(channel.has_namespace("http://example.com/ext"), Some("ext"));
+    /// assert_eq!(channel.has_namespace("http://example.com/other"), None);
+    /// ```
+    pub fn has_namespace(&self, uri: &str) -> Option<&str> {
+        self.namespaces
+            .iter()
+            .find(|(_, value)| value.as_str() == uri)
+            .map(|(prefix, _)| prefix.as_str())
+    }
+
+    /// Return a summary of this channel for display.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_title("Channel Title");
+    /// channel.set_generator("Generator".to_string());
+    ///
+    /// let metadata = channel.metadata();
+    /// assert_eq!(metadata.title, "Channel Title");
+    /// assert_eq!(metadata.generator, Some("Generator"));
+    /// assert_eq!(metadata.language, None);
+    /// ```
+    pub fn metadata(&self) -> ChannelMetadata<'_> {
+        ChannelMetadata {
+            title: self.title(),
+            link: self.link(),
+            description: self.description(),
+            language: self.language(),
+            generator: self.generator(),
+            docs: self.docs(),
+            last_build_date: self.last_build_date(),
+            #[cfg(feature = "validation")]
+            last_build_date_parsed: self.last_build_date_parsed().and_then(Result::ok),
+        }
+    }
+
+    /// Compare the items of this channel with those of a previous version of it.
+    ///
+    /// Items are matched by their identity: the GUID value if the item has a GUID, otherwise
+    /// its link. An item with neither only matches an identical item. Each item of `previous` is
+    /// matched at most once, items that share an identity are matched in order.
+    ///
+    /// Added items are returned in the order of this channel, removed items in the order of
+    /// `previous` and changed items, as `(current, previous)` pairs, in the order of this
+    /// channel. An item has changed if `Item::diff` reports any field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, Item};
+    ///
+    /// let mut item = Item::default();
+    /// item.set_link("http://example.com/1".to_string());
+    /// let previous = Channel::from(item.clone());
+    ///
+    /// item.set_title("New title".to_string());
+    /// let current = Channel::from(item);
+    ///
+    /// let diff = current.diff(&previous);
+    /// assert!(diff.added.is_empty());
+    /// assert!(diff.removed.is_empty());
+    /// assert_eq!(diff.changed.len(), 1);
+    /// ```
+    pub fn diff<'a>(&'a self, previous: &'a Channel) -> ChannelDiff<'a> {
+        fn identity(item: &Item) -> Option<&str> {
+            item.guid().map(|guid| guid.value()).or_else(|| item.link())
+        }
+
+        let mut by_identity: HashMap<&str, VecDeque<usize>> = HashMap::new();
+        for (index, item) in previous.items.iter().enumerate() {
+            if let Some(key) = identity(item) {
+                by_identity.entry(key).or_default().push_back(index);
+            }
+        }
+
+        let mut diff = ChannelDiff::default();
+        let mut matched = vec![false; previous.items.len()];
+        for item in &self.items {
+            let index = match identity(item) {
+                Some(key) => by_identity.get_mut(key).and_then(VecDeque::pop_front),
+                None => previous
+                    .items
+                    .iter()
+                    .zip(&matched)
+                    .position(|(old, matched)| !matched && old.diff(item).is_empty()),
+            };
+            match index {
+                Some(index) => {
+                    matched[index] = true;
+                    let old = &previous.items[index];
+                    if !item.diff(old).is_empty() {
+                        diff.changed.push((item, old));
+                    }
+                }
+                None => diff.added.push(item),
+            }
+        }
+        diff.removed = previous
+            .items
+            .iter()
+            .zip(matched)
+            .filter(|(_, matched)| !matched)
+            .map(|(item, _)| item)
+            .collect();
+        diff
+    }
+}
+
+/// The items that differ between two versions of a channel, as returned by `Channel::diff`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ChannelDiff<'a> {
+    /// The items that are new in the current channel.
+    pub added: Vec<&'a Item>,
+    /// The items of the previous channel that are no longer present.
+    pub removed: Vec<&'a Item>,
+    /// The items present in both channels that differ, as `(current, previous)` pairs.
+    pub changed: Vec<(&'a Item, &'a Item)>,
+}
+
+/// A summary of a channel for display, borrowed from the channel, as returned by
+/// `Channel::metadata`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelMetadata<'a> {
+    /// The name of the channel.
+    pub title: &'a str,
+    /// The URL for the website corresponding to the channel.
+    pub link: &'a str,
+    /// The description of the channel.
+    pub description: &'a str,
+    /// The language of the channel.
+    pub language: Option<&'a str>,
+    /// The program used to generate the channel.
+    pub generator: Option<&'a str>,
+    /// The URL for the documentation of the RSS format.
+    pub docs: Option<&'a str>,
+    /// The time the content of the channel was last changed.
+    pub last_build_date: Option<&'a str>,
+    /// The time the content of the channel was last changed, if it can be parsed.
+    #[cfg(feature = "validation")]
+    pub last_build_date_parsed: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+
+/// The format of an RSS document, as detected from its root element.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedFormat {
+    /// RSS 0.90, 0.91 or 0.92.
+    Rss09,
+    /// RSS 1.0, an RDF document.
+    Rss1,
+    /// RSS 2.0, also assumed for an `rss` element without a version.
+    #[default]
+    Rss2,
+}
+
+/// The elements read from inside the root element of a document.
+#[derive(Default)]
+struct RootElements {
+    channel: Option<Channel>,
+    // byte ranges of the items inside and outside of the channel
+    channel_item_spans: Vec<Range<u64>>,
+    item_spans: Vec<Range<u64>>,
+    // for parsing RSS 0.9, 1.0 feeds
+    items: Option<Vec<Item>>,
+    image: Option<Image>,
+    text_input: Option<TextInput>,
+}
+
+impl RootElements {
+    fn read<R: BufRead>(
+        &mut self,
+        reader: &mut Reader<R>,
+        namespaces: &BTreeMap<String, String>,
+        options: &ReadOptions,
+    ) -> Result<(), Error> {
+        let mut buf = Vec::new();
+
+        loop {
+            let position = reader.buffer_position();
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(element) => match core_element_name(
+                    decode(element.name().as_ref(), reader)?.as_ref(),
+                    namespaces,
+                ) {
+                    "channel" => {
+                        let channel = self
+                            .channel
+                            .insert(Channel::with_item_capacity(options.expected_items));
+                        Channel::from_xml_with_options(
+                            channel,
+                            namespaces,
+                            reader,
+                            element.attributes(),
+                            options,
+                            &mut self.channel_item_spans,
+                        )?;
+                    }
+                    "item" => {
+                        let item = Item::from_xml_with_options(
+                            namespaces,
+                            reader,
+                            element.attributes(),
+                            options,
+                        )?;
+                        if options.capture_raw_items {
+                            self.item_spans.push(position..reader.buffer_position());
+                        }
+                        self.items
+                            .get_or_insert_with(|| Vec::with_capacity(options.expected_items))
+                            .push(item);
+                    }
+                    "image" => {
+                        let inner = Image::from_xml(reader, element.attributes())?;
+                        self.image = Some(inner);
+                    }
+                    "textinput" => {
+                        let inner = TextInput::from_xml(reader, element.attributes())?;
+                        self.text_input = Some(inner);
+                    }
+                    _ => skip(element.name(), reader)?,
+                },
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok(())
+    }
+
+    fn into_channel(self) -> Option<Channel> {
+        // RSS 1.0 resource lists may omit the channel and only list items, in which case a
+        // default channel holding the items is returned.
+        let mut channel = match (self.channel, &self.items) {
+            (None, Some(_)) => Channel::default(),
+            (channel, _) => channel?,
+        };
+
+        if let Some(mut items) = self.items {
+            channel.items.append(&mut items);
+        }
+
+        if self.image.is_some() {
+            channel.image = self.image;
+        }
+
+        if self.text_input.is_some() {
+            channel.text_input = self.text_input;
+        }
+
+        Some(channel)
+    }
+}
+
+impl FeedFormat {
+    fn detect<R: BufRead>(
+        name: &str,
+        reader: &Reader<R>,
+        mut atts: Attributes,
+    ) -> Result<FeedFormat, Error> {
+        for attr in atts.with_checks(false).flatten() {
+            let key = decode(attr.key.as_ref(), reader)?;
+            match (name, key.as_ref()) {
+                ("rss", "version") => {
+                    let version = attr_value(&attr, reader)?;
+                    if version.trim().starts_with("0.9") {
+                        return Ok(FeedFormat::Rss09);
+                    }
+                }
+                // RSS 0.90 is an RDF document in the Netscape namespace
+                ("rdf:RDF", "xmlns") if attr_value(&attr, reader)?.contains("/rdf/simple/0.9") => {
+                    return Ok(FeedFormat::Rss09);
+                }
+                _ => {}
+            }
+        }
+        Ok(match name {
+            "rdf:RDF" => FeedFormat::Rss1,
+            _ => FeedFormat::Rss2,
+        })
+    }
+}
+
+/// Returns true if the contents of a `<!DOCTYPE ...>` declaration name an HTML document.
+fn is_html_doctype(doctype: &str) -> bool {
+    doctype
+        .trim_start()
+        .get(..4)
+        .is_some_and(|name| name.eq_ignore_ascii_case("html"))
+}
+
+/// The number of bytes of text outside any element that are skipped while looking for the root
+/// element.
+const MAX_TEXT_BEFORE_ROOT: usize = 4096;
+
+/// The root element of a document and what was read before it.
+struct DocumentRoot {
+    namespaces: BTreeMap<String, String>,
+    source_format: FeedFormat,
+    xml_declaration: Option<XmlDeclaration>,
+    processing_instructions: Vec<String>,
+}
+
+impl DocumentRoot {
+    /// Reads up to and including the start tag of the `<rss>` or `<rdf:RDF>` element.
+    fn read<R: BufRead>(reader: &mut Reader<R>, options: &ReadOptions) -> Result<Self, Error> {
+        let mut xml_declaration = None;
+        let mut processing_instructions = Vec::new();
+        let mut buf = Vec::new();
+        // the namespaces declared by each wrapper element that is open
+        let mut scopes = vec![BTreeMap::new()];
+        let mut text_len = 0;
+
+        loop {
+            let depth = scopes.len() - 1;
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(element) => match decode(element.name().as_ref(), reader)?.as_ref() {
+                    name @ ("rss" | "rdf:RDF") => {
+                        let source_format = FeedFormat::detect(name, reader, element.attributes())?;
+                        let namespaces = read_namespace_declarations(
+                            reader,
+                            element.attributes(),
+                            &scopes[depth],
+                        )?
+                        .into_owned();
+                        return Ok(DocumentRoot {
+                            namespaces,
+                            source_format,
+                            xml_declaration,
+                            processing_instructions,
+                        });
+                    }
+                    name if depth == 0 && name.eq_ignore_ascii_case("html") => {
+                        return Err(Error::NotAFeed)
+                    }
+                    _ if depth < options.root_search_depth => {
+                        let namespaces = read_namespace_declarations(
+                            reader,
+                            element.attributes(),
+                            &scopes[depth],
+                        )?
+                        .into_owned();
+                        scopes.push(namespaces);
+                    }
+                    _ if depth > 0 => skip(element.name(), reader)?,
+                    _ => {
+                        return Err(Error::InvalidStartTag);
+                    }
+                },
+                Event::End(_) if depth > 0 => {
+                    scopes.pop();
+                }
+                Event::DocType(doctype) if is_html_doctype(&decode(&doctype, reader)?) => {
+                    return Err(Error::NotAFeed);
+                }
+                Event::Decl(decl) => {
+                    let declaration = XmlDeclaration::from_xml(reader, &decl)?;
+                    if let (Some(encoding), None) = (declaration.encoding(), decl.encoder()) {
+                        return Err(Error::UnsupportedEncoding(encoding.to_string()));
+                    }
+                    xml_declaration = Some(declaration);
+                }
+                Event::PI(pi) if options.preserve_processing_instructions => {
+                    processing_instructions.push(decode(&pi, reader)?.into_owned());
+                }
+                // the text inside wrapper elements is their content and is not limited
+                Event::Text(text) if depth == 0 => {
+                    text_len += text.len();
+                    if text_len > MAX_TEXT_BEFORE_ROOT {
+                        return Err(Error::InvalidStartTag);
+                    }
+                }
+                Event::Eof => return Err(Error::Eof),
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+}
+
+impl Channel {
+    /// Attempt to read an RSS channel from a reader.
+    ///
+    /// Up to 4 KiB of text before the root element, such as blank lines or a status line that a
+    /// misconfigured server wrote into the body, is ignored; more is an error. Markup other than
+    /// the XML declaration, comments and processing instructions before the root element is an
+    /// error. To read a feed wrapped in other elements, set `ReadOptions::root_search_depth` and
+    /// use `read_from_with_options`.
+    ///
+    /// The input is decoded using the encoding named by the XML declaration. UTF-16 input is
+    /// recognised by its byte order mark or by its XML declaration. Declaring an encoding that
+    /// cannot be decoded is an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let reader: BufRead = ...;
+    /// let channel = Channel::read_from(reader).unwrap();
+    /// ```
+    pub fn read_from<R: BufRead>(reader: R) -> Result<Channel, Error> {
+        Channel::read_from_with_options(reader, &ReadOptions::default())
+    }
+
+    /// Attempt to read an RSS channel from a reader using the given options.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let reader: BufRead = ...;
+    /// let options = ReadOptions::default();
+    /// let channel = Channel::read_from_with_options(reader, &options).unwrap();
+    /// ```
+    pub fn read_from_with_options<R: BufRead>(
+        reader: R,
+        options: &ReadOptions,
+    ) -> Result<Channel, Error> {
+        Channel::read_document(reader, options, &mut None)
+    }
+
+    /// Attempt to read an RSS channel from a reader, keeping whatever was read before an error.
+    ///
+    /// Returns the channel read so far together with the error that stopped reading, if any.
+    /// Items are only included once they have been read completely. If the error occurred before
+    /// any channel content was read, the returned channel is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = "<rss><channel><item><title>First</title></item><item><title>Sec";
+    /// let (channel, error) = Channel::read_from_partial(input.as_bytes());
+    /// assert_eq!(channel.items().len(), 1);
+    /// assert!(error.is_some());
+    /// ```
+    pub fn read_from_partial<R: BufRead>(reader: R) -> (Channel, Option<Error>) {
+        let mut partial = None;
+        match Channel::read_document(reader, &ReadOptions::default(), &mut partial) {
+            Ok(channel) => (channel, None),
+            Err(err) => (partial.unwrap_or_default(), Some(err)),
+        }
+    }
+
+    /// Reads a document, storing the channel read so far in `partial` if reading fails after the
+    /// root element.
+    fn read_document<R: BufRead>(
+        mut reader: R,
+        options: &ReadOptions,
+        partial: &mut Option<Channel>,
+    ) -> Result<Channel, Error> {
+        if let Some(mut text) = read_utf16(&mut reader)? {
+            // the reader would decode the text as UTF-16 again if the declaration still said so
+            let declared = declare_utf8(&mut text);
+            let restore = |channel: &mut Channel| {
+                if let Some(decl) = channel.xml_declaration.as_mut() {
+                    decl.encoding.clone_from(&declared);
+                }
+            };
+            let result = Channel::read_document(text.as_bytes(), options, partial);
+            if let Some(channel) = partial.as_mut() {
+                restore(channel);
+            }
+            return result.map(|mut channel| {
+                restore(&mut channel);
+                channel
+            });
+        }
+
+        let mut reader = Reader::from_reader(Recorder::new(reader, options.capture_raw_items));
+        let config = reader.config_mut();
+        config.trim_text(options.trim_text);
+        config.expand_empty_elements = true;
+        let DocumentRoot {
+            namespaces,
+            source_format,
+            xml_declaration,
+            processing_instructions,
+        } = DocumentRoot::read(&mut reader, options)?;
+
+        let mut elements = RootElements::default();
+        let result = elements.read(&mut reader, &namespaces, options);
+        let channel_item_spans = std::mem::take(&mut elements.channel_item_spans);
+        let item_spans = std::mem::take(&mut elements.item_spans);
+
+        let channel = elements.into_channel().map(|mut channel| {
+            channel.namespaces = namespaces;
+            channel.source_format = source_format;
+            channel.processing_instructions = processing_instructions;
+            channel.xml_declaration = xml_declaration;
+            channel
+        });
+
+        if let Err(err) = result {
+            *partial = channel;
+            return Err(err);
+        }
+
+        let mut channel = channel.ok_or(Error::Eof)?;
+
+        if options.capture_raw_items {
+            let source = reader.get_ref().recorded();
+            let spans = channel_item_spans.into_iter().chain(item_spans);
+            for (item, span) in channel.items.iter_mut().zip(spans) {
+                let bytes = &source[span.start as usize..span.end as usize];
+                item.raw_xml = Some(reader.decoder().decode(bytes)?.into_owned());
+            }
+        }
+
+        if options.promote_atom_enclosures {
+            #[cfg(feature = "atom")]
+            for item in &mut channel.items {
+                item.promote_atom_enclosure();
+            }
+        }
+
+        if options.promote_atom_id {
+            #[cfg(feature = "atom")]
+            for item in &mut channel.items {
+                item.promote_atom_id();
+            }
+        }
+
+        Ok(channel)
+    }
+
+    /// Count the items of an RSS channel without parsing them.
+    ///
+    /// Items are counted the same way `read_from` reads them: inside the `<channel>` element
+    /// and, for RSS 0.9 and 1.0, next to it. Atom `<entry>` elements are not items and are not
+    /// counted, as `read_from` does not read them either.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::collections::BTreeMap;
     /// use rss::Channel;
     ///
-    /// let mut namespaces = BTreeMap::new();
-    /// namespaces.insert("ext".to_string(), "http://example.com".to_string());
-    ///
-    /// let mut channel = Channel::default();
-    /// channel.set_namespaces(namespaces);
-    /// assert_eq!(channel.namespaces().get("ext").map(String::as_str), Some("http://example.com"));
+    /// let input = r#"<rss><channel><item/><item><title>Title</title></item></channel></rss>"#;
+    /// assert_eq!(Channel::count_items(input.as_bytes()).unwrap(), 2);
     /// ```
-    pub fn namespaces(&self) -> &BTreeMap<String, String> {
-        &self.namespaces
+    pub fn count_items<R: BufRead>(reader: R) -> Result<usize, Error> {
+        Channel::count_items_with_options(reader, &ReadOptions::default())
     }
 
-    /// Set the namespaces for this channel.
+    /// Count the items of an RSS channel without parsing them, finding the root element as
+    /// `read_from_with_options` does with the given options.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::collections::BTreeMap;
-    /// use rss::Channel;
-    ///
-    /// let mut channel = Channel::default();
-    /// channel.set_namespaces(BTreeMap::new());
-    /// ```
-    pub fn set_namespaces<V>(&mut self, namespaces: V)
-    where
-        V: Into<BTreeMap<String, String>>,
-    {
-        self.namespaces = namespaces.into()
-    }
-}
-
-impl Channel {
-    /// Attempt to read an RSS channel from a reader.
+    /// use rss::{Channel, ReadOptions};
     ///
-    /// # Example
-    ///
-    /// ```rust,ignore
-    /// let reader: BufRead = ...;
-    /// let channel = Channel::read_from(reader).unwrap();
+    /// let input = r#"<response><rss><channel><item/></channel></rss></response>"#;
+    /// let options = ReadOptions {
+    ///     root_search_depth: 1,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     Channel::count_items_with_options(input.as_bytes(), &options).unwrap(),
+    ///     1
+    /// );
     /// ```
-    pub fn read_from<R: BufRead>(reader: R) -> Result<Channel, Error> {
+    pub fn count_items_with_options<R: BufRead>(
+        reader: R,
+        options: &ReadOptions,
+    ) -> Result<usize, Error> {
         let mut reader = Reader::from_reader(reader);
         reader.config_mut().expand_empty_elements = true;
-        let namespaces;
+        let namespaces = DocumentRoot::read(&mut reader, options)?.namespaces;
         let mut buf = Vec::new();
+        let mut has_channel = false;
+        let mut count = 0;
 
-        let mut channel: Option<Channel> = None;
-
-        // for parsing RSS 0.9, 1.0 feeds
-        let mut items: Option<Vec<Item>> = None;
-        let mut image: Option<Image> = None;
-        let mut text_input: Option<TextInput> = None;
-
-        // find opening element
         loop {
             match reader.read_event_into(&mut buf)? {
-                Event::Start(element) => match decode(element.name().as_ref(), &reader)?.as_ref() {
-                    "rss" | "rdf:RDF" => {
-                        namespaces = read_namespace_declarations(
+                Event::Start(element) => match core_element_name(
+                    decode(element.name().as_ref(), &reader)?.as_ref(),
+                    &namespaces,
+                ) {
+                    "channel" => {
+                        let namespaces = read_namespace_declarations(
                             &mut reader,
                             element.attributes(),
-                            &BTreeMap::new(),
-                        )?
-                        .into_owned();
-                        break;
+                            &namespaces,
+                        )?;
+                        count += Channel::count_channel_items(&mut reader, &namespaces)?;
+                        has_channel = true;
                     }
-                    _ => {
-                        return Err(Error::InvalidStartTag);
+                    "item" => {
+                        count += 1;
+                        skip(element.name(), &mut reader)?;
                     }
+                    _ => skip(element.name(), &mut reader)?,
                 },
-                Event::Eof => return Err(Error::Eof),
-                _ => continue,
+                Event::End(_) | Event::Eof => break,
+                _ => {}
             }
+            buf.clear();
+        }
+
+        if has_channel || count > 0 {
+            Ok(count)
+        } else {
+            Err(Error::Eof)
         }
+    }
+
+    /// Count the `item` children of a channel element, consuming the element.
+    fn count_channel_items<R: BufRead>(
+        reader: &mut Reader<R>,
+        namespaces: &BTreeMap<String, String>,
+    ) -> Result<usize, Error> {
+        let mut buf = Vec::new();
+        let mut count = 0;
 
         loop {
             match reader.read_event_into(&mut buf)? {
-                Event::Start(element) => match decode(element.name().as_ref(), &reader)?.as_ref() {
-                    "channel" => {
-                        let inner =
-                            Channel::from_xml(&namespaces, &mut reader, element.attributes())?;
-                        channel = Some(inner);
-                    }
-                    "item" => {
-                        let item = Item::from_xml(&namespaces, &mut reader, element.attributes())?;
-                        if items.is_none() {
-                            items = Some(Vec::new());
-                        }
-                        items.as_mut().unwrap().push(item);
+                Event::Start(element) => {
+                    let name = decode(element.name().as_ref(), reader)?.into_owned();
+                    if core_element_name(&name, namespaces) == "item" {
+                        count += 1;
                     }
-                    "image" => {
-                        let inner = Image::from_xml(&mut reader, element.attributes())?;
-                        image = Some(inner);
-                    }
-                    "textinput" => {
-                        let inner = TextInput::from_xml(&mut reader, element.attributes())?;
-                        text_input = Some(inner);
-                    }
-                    _ => skip(element.name(), &mut reader)?,
-                },
-                Event::End(_) | Event::Eof => break,
+                    skip(element.name(), reader)?;
+                }
+                Event::End(_) => break,
+                Event::Eof => return Err(Error::Eof),
                 _ => {}
             }
             buf.clear();
         }
 
-        if let Some(mut channel) = channel {
-            if let Some(mut items) = items {
-                channel.items.append(&mut items);
-            }
-
-            if image.is_some() {
-                channel.image = image;
-            }
+        Ok(count)
+    }
 
-            if text_input.is_some() {
-                channel.text_input = text_input;
-            }
+    /// The namespaces declared for the channel, the ones used by its contents and the ones it
+    /// was read with.
+    fn declared_namespaces(&self) -> BTreeMap<String, String> {
+        let mut namespaces = BTreeMap::new();
+        namespaces.extend(self.used_namespaces());
+        namespaces.extend(self.namespaces.clone());
+        namespaces
+    }
 
-            channel.namespaces = namespaces;
+    fn write<W: Write>(&self, mut writer: Writer<W>, options: &WriteOptions) -> Result<W, Error> {
+        let namespaces = self.declared_namespaces();
 
-            Ok(channel)
-        } else {
-            Err(Error::Eof)
+        match (options.namespace_placement, options.escape) {
+            (NamespacePlacement::Root, Escape::Full) => {
+                self.write_document(&mut writer, &namespaces, options)?
+            }
+            (NamespacePlacement::Root, escape) => {
+                let mut document = Writer::new(Vec::new());
+                self.write_document(&mut document, &namespaces, options)?;
+                let document = document.into_inner();
+                rewrite_document(&document, &BTreeMap::new(), escape, &mut writer)?;
+            }
+            (NamespacePlacement::Element, escape) => {
+                let mut document = Writer::new(Vec::new());
+                self.write_document(&mut document, &namespaces, options)?;
+                let document = document.into_inner();
+                rewrite_document(&document, &namespaces, escape, &mut writer)?;
+            }
         }
+
+        Ok(writer.into_inner())
     }
 
-    fn write<W: Write>(&self, mut writer: Writer<W>) -> Result<W, Error> {
-        writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+    fn write_document<W: Write>(
+        &self,
+        writer: &mut Writer<W>,
+        namespaces: &BTreeMap<String, String>,
+        options: &WriteOptions,
+    ) -> Result<(), Error> {
+        if options.write_declaration {
+            let declaration = self.xml_declaration.clone().unwrap_or_default();
+            writer.write_event(Event::Decl(declaration.to_bytes_decl()))?;
+        }
+        for pi in &self.processing_instructions {
+            if pi.contains("?>") {
+                return Err(Error::InvalidValue {
+                    field: "processing_instructions",
+                    value: pi.clone(),
+                });
+            }
+            writer.write_event(Event::PI(BytesPI::new(pi.as_str())))?;
+        }
 
         let name = "rss";
         let mut element = BytesStart::new(name);
         element.push_attribute(("version", "2.0"));
 
-        let used_namespaces = self.used_namespaces();
-        let mut namespaces: BTreeMap<&String, &String> = BTreeMap::new();
-        namespaces.extend(&used_namespaces);
-        namespaces.extend(&self.namespaces);
-        for (name, url) in namespaces {
-            element.push_attribute((format!("xmlns:{}", name).as_bytes(), url.as_bytes()));
+        if options.namespace_placement == NamespacePlacement::Root {
+            for (name, url) in namespaces {
+                element.push_attribute((format!("xmlns:{}", name).as_bytes(), url.as_bytes()));
+            }
         }
 
         writer.write_event(Event::Start(element))?;
 
-        self.to_xml(&mut writer)?;
+        self.write_channel(writer, namespaces, options)?;
 
         writer.write_event(Event::End(BytesEnd::new(name)))?;
 
-        Ok(writer.into_inner())
+        Ok(())
     }
 
     /// Attempt to write the RSS channel as XML to a writer.
@@ -1153,7 +2594,28 @@ impl Channel {
     /// channel.write_to(writer).unwrap();
     /// ```
     pub fn write_to<W: Write>(&self, writer: W) -> Result<W, Error> {
-        self.write(::quick_xml::Writer::new(writer))
+        self.write_to_with(writer, &WriteOptions::default())
+    }
+
+    /// Attempt to write the RSS channel as XML to a writer using the given options.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let channel: Channel = ...;
+    /// let writer: Write = ...;
+    /// let options = WriteOptions::default();
+    /// channel.write_to_with(writer, &options).unwrap();
+    /// ```
+    pub fn write_to_with<W: Write>(&self, writer: W, options: &WriteOptions) -> Result<W, Error> {
+        let writer = match options.indent {
+            Some(indent) => {
+                let (indent_char, indent_size) = indent.char_and_size();
+                ::quick_xml::Writer::new_with_indent(writer, indent_char, indent_size)
+            }
+            None => ::quick_xml::Writer::new(writer),
+        };
+        self.write(writer, options)
     }
 
     /// Attempt to write the RSS channel as pretty XML to a writer.
@@ -1171,11 +2633,130 @@ impl Channel {
         indent_char: u8,
         indent_size: usize,
     ) -> Result<W, Error> {
-        self.write(::quick_xml::Writer::new_with_indent(
-            writer,
-            indent_char,
-            indent_size,
-        ))
+        self.write(
+            ::quick_xml::Writer::new_with_indent(writer, indent_char, indent_size),
+            &WriteOptions::default(),
+        )
+    }
+
+    /// Attempt to write the RSS channel as pretty XML to a writer using the given indentation.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let channel: Channel = ...;
+    /// let writer: Write = ...;
+    /// channel.pretty_write_to_indent(writer, Indent::Tabs(1)).unwrap();
+    /// ```
+    pub fn pretty_write_to_indent<W: Write>(&self, writer: W, indent: Indent) -> Result<W, Error> {
+        let options = WriteOptions {
+            indent: Some(indent),
+            ..Default::default()
+        };
+        self.write_to_with(writer, &options)
+    }
+
+    /// Write the RSS channel as XML to a byte vector.
+    ///
+    /// This is the byte counterpart of `to_string`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let channel = Channel::default();
+    /// assert_eq!(channel.to_bytes(), channel.to_string().into_bytes());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.write_to(Vec::new()).unwrap_or_default()
+    }
+
+    /// Write the RSS channel as pretty XML to a byte vector using the given indentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, Indent};
+    ///
+    /// let channel = Channel::default();
+    /// let bytes = channel.to_pretty_bytes(Indent::Spaces(2));
+    /// assert!(bytes.starts_with(b"<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<rss"));
+    /// ```
+    pub fn to_pretty_bytes(&self, indent: Indent) -> Vec<u8> {
+        self.pretty_write_to_indent(Vec::new(), indent)
+            .unwrap_or_default()
+    }
+}
+
+impl PartialEq for Channel {
+    fn eq(&self, other: &Self) -> bool {
+        // the declaration and source format are left out, a channel read from RSS 0.91 without
+        // a declaration equals the same channel written and read back as RSS 2.0
+        let Channel {
+            title,
+            link,
+            description,
+            language,
+            xml_lang,
+            copyright,
+            managing_editor,
+            webmaster,
+            pub_date,
+            last_build_date,
+            categories,
+            generator,
+            docs,
+            cloud,
+            rating,
+            ttl,
+            image,
+            text_input,
+            skip_hours,
+            skip_days,
+            items,
+            extensions,
+            #[cfg(feature = "atom")]
+            atom_ext,
+            itunes_ext,
+            dublin_core_ext,
+            syndication_ext,
+            namespaces,
+            xml_declaration: _,
+            source_format: _,
+            processing_instructions,
+        } = self;
+        #[cfg(feature = "atom")]
+        if *atom_ext != other.atom_ext {
+            return false;
+        }
+        *title == other.title
+            && *link == other.link
+            && *description == other.description
+            && *language == other.language
+            && *xml_lang == other.xml_lang
+            && *copyright == other.copyright
+            && *managing_editor == other.managing_editor
+            && *webmaster == other.webmaster
+            && *pub_date == other.pub_date
+            && *last_build_date == other.last_build_date
+            && *categories == other.categories
+            && *generator == other.generator
+            && *docs == other.docs
+            && *cloud == other.cloud
+            && *rating == other.rating
+            && *ttl == other.ttl
+            && *image == other.image
+            && *text_input == other.text_input
+            && *skip_hours == other.skip_hours
+            && *skip_days == other.skip_days
+            && *items == other.items
+            && *extensions == other.extensions
+            && *itunes_ext == other.itunes_ext
+            && *dublin_core_ext == other.dublin_core_ext
+            && *syndication_ext == other.syndication_ext
+            && *namespaces == other.namespaces
+            && *processing_instructions == other.processing_instructions
     }
 }
 
@@ -1194,16 +2775,78 @@ impl Channel {
         reader: &mut Reader<R>,
         atts: Attributes,
     ) -> Result<Self, Error> {
+        let options = ReadOptions::default();
         let mut channel = Channel::default();
+        Channel::from_xml_with_options(
+            &mut channel,
+            namespaces,
+            reader,
+            atts,
+            &options,
+            &mut Vec::new(),
+        )?;
+        Ok(channel)
+    }
+
+    fn from_xml_with_options<R: BufRead>(
+        channel: &mut Channel,
+        namespaces: &BTreeMap<String, String>,
+        reader: &mut Reader<R>,
+        atts: Attributes,
+        options: &ReadOptions,
+        item_spans: &mut Vec<Range<u64>>,
+    ) -> Result<(), Error> {
         let mut extensions = ExtensionMap::new();
-        let mut buf = Vec::new();
-        let mut skip_buf = Vec::new();
 
+        channel.xml_lang = xml_lang(reader, atts.clone())?;
         let namespaces = read_namespace_declarations(reader, atts, namespaces)?;
 
+        // the extensions read before an error are kept for partial reads
+        let result = Channel::read_elements(
+            channel,
+            &mut extensions,
+            &namespaces,
+            reader,
+            options,
+            item_spans,
+        );
+
+        // Process each of the namespaces we know
+        #[cfg(feature = "atom")]
+        if let Some(v) = extensions.remove(atom::NAMESPACE) {
+            channel.atom_ext = Some(atom::AtomExtension::from_map(v));
+        }
+        if let Some(v) = extensions.remove(itunes::NAMESPACE) {
+            channel.itunes_ext = Some(itunes::ITunesChannelExtension::from_map(v));
+        }
+        if let Some(v) = extensions.remove(dublincore::NAMESPACE) {
+            channel.dublin_core_ext = Some(dublincore::DublinCoreExtension::from_map(v));
+        }
+        if let Some(v) = extensions.remove(syndication::NAMESPACE) {
+            channel.syndication_ext = Some(syndication::SyndicationExtension::from_map(v));
+        }
+
+        result
+    }
+
+    fn read_elements<R: BufRead>(
+        channel: &mut Channel,
+        extensions: &mut ExtensionMap,
+        namespaces: &BTreeMap<String, String>,
+        reader: &mut Reader<R>,
+        options: &ReadOptions,
+        item_spans: &mut Vec<Range<u64>>,
+    ) -> Result<(), Error> {
+        let mut buf = Vec::new();
+        let mut skip_buf = Vec::new();
+
         loop {
+            let position = reader.buffer_position();
             match reader.read_event_into(&mut buf)? {
-                Event::Start(element) => match decode(element.name().as_ref(), reader)?.as_ref() {
+                Event::Start(element) => match core_element_name(
+                    decode(element.name().as_ref(), reader)?.as_ref(),
+                    namespaces,
+                ) {
                     "category" => {
                         let category = Category::from_xml(reader, element.attributes())?;
                         channel.categories.push(category);
@@ -1221,52 +2864,66 @@ impl Channel {
                         channel.text_input = Some(text_input);
                     }
                     "item" => {
-                        let item =
-                            Item::from_xml(namespaces.as_ref(), reader, element.attributes())?;
+                        let item = Item::from_xml_with_options(
+                            namespaces,
+                            reader,
+                            element.attributes(),
+                            options,
+                        )?;
+                        if options.capture_raw_items {
+                            item_spans.push(position..reader.buffer_position());
+                        }
                         channel.items.push(item);
                     }
                     "title" => {
-                        if let Some(content) = element_text(reader)? {
+                        if let Some(content) = element_text_with(reader, options)? {
                             channel.title = content;
                         }
                     }
                     "link" => {
-                        if let Some(content) = element_text(reader)? {
+                        if let Some(content) = element_text_with(reader, options)? {
                             channel.link = content;
                         }
                     }
                     "description" => {
-                        if let Some(content) = element_text(reader)? {
+                        if let Some(content) = element_text_with(reader, options)? {
                             channel.description = content;
                         }
                     }
-                    "language" => channel.language = element_text(reader)?,
-                    "copyright" => channel.copyright = element_text(reader)?,
+                    "language" => channel.language = element_text_with(reader, options)?,
+                    "copyright" => channel.copyright = element_text_with(reader, options)?,
                     "managingEditor" => {
-                        channel.managing_editor = element_text(reader)?;
+                        channel.managing_editor = element_text_with(reader, options)?;
                     }
-                    "webMaster" => channel.webmaster = element_text(reader)?,
-                    "pubDate" => channel.pub_date = element_text(reader)?,
+                    "webMaster" => channel.webmaster = element_text_with(reader, options)?,
+                    "pubDate" => channel.pub_date = element_text_with(reader, options)?,
                     "lastBuildDate" => {
-                        channel.last_build_date = element_text(reader)?;
+                        channel.last_build_date = element_text_with(reader, options)?;
+                    }
+                    "generator" => channel.generator = element_text_with(reader, options)?,
+                    "rating" => channel.rating = element_text_with(reader, options)?,
+                    "docs" => channel.docs = element_text_with(reader, options)?,
+                    "ttl" => {
+                        channel.ttl = element_text_with(reader, options)?
+                            .map(|ttl| checked(options, "ttl", ttl, is_valid_ttl))
+                            .transpose()?;
                     }
-                    "generator" => channel.generator = element_text(reader)?,
-                    "rating" => channel.rating = element_text(reader)?,
-                    "docs" => channel.docs = element_text(reader)?,
-                    "ttl" => channel.ttl = element_text(reader)?,
                     "skipHours" => loop {
                         skip_buf.clear();
                         match reader.read_event_into(&mut skip_buf)? {
                             Event::Start(element) => {
                                 if decode(element.name().as_ref(), reader)?.as_ref() == "hour" {
-                                    if let Some(content) = element_text(reader)? {
-                                        channel.skip_hours.push(content);
+                                    if let Some(content) = element_text_with(reader, options)? {
+                                        let hour =
+                                            checked(options, "hour", content, is_valid_hour)?;
+                                        channel.skip_hours.push(hour);
                                     }
                                 } else {
                                     skip(element.name(), reader)?;
                                 }
                             }
-                            Event::End(_) | Event::Eof => break,
+                            Event::End(_) => break,
+                            Event::Eof => return Err(Error::Eof),
                             _ => {}
                         }
                     },
@@ -1275,14 +2932,16 @@ impl Channel {
                         match reader.read_event_into(&mut skip_buf)? {
                             Event::Start(element) => {
                                 if decode(element.name().as_ref(), reader)?.as_ref() == "day" {
-                                    if let Some(content) = element_text(reader)? {
-                                        channel.skip_days.push(content);
+                                    if let Some(content) = element_text_with(reader, options)? {
+                                        let day = checked(options, "day", content, is_valid_day)?;
+                                        channel.skip_days.push(day);
                                     }
                                 } else {
                                     skip(element.name(), reader)?;
                                 }
                             }
-                            Event::End(_) | Event::Eof => break,
+                            Event::End(_) => break,
+                            Event::Eof => return Err(Error::Eof),
                             _ => {}
                         }
                     },
@@ -1291,25 +2950,30 @@ impl Channel {
                             let scope_namespases = read_namespace_declarations(
                                 reader,
                                 element.attributes(),
-                                namespaces.as_ref(),
+                                namespaces,
                             )?;
                             let ext_ns = scope_namespases.get(prefix).map(|s| s.as_str());
                             let ext = parse_extension_element(reader, element.attributes())?;
                             match ext_ns {
                                 #[cfg(feature = "atom")]
                                 Some(ns @ atom::NAMESPACE) => {
-                                    extension_entry(&mut extensions, ns, name).push(ext);
+                                    extension_entry(extensions, ns, name).push(ext);
                                 }
                                 Some(ns) if is_itunes_namespace(ns) => {
-                                    extension_entry(&mut extensions, itunes::NAMESPACE, name)
-                                        .push(ext);
+                                    extension_entry(extensions, itunes::NAMESPACE, name).push(ext);
                                 }
                                 Some(ns @ dublincore::NAMESPACE)
                                 | Some(ns @ syndication::NAMESPACE) => {
-                                    extension_entry(&mut extensions, ns, name).push(ext);
+                                    extension_entry(extensions, ns, name).push(ext);
                                 }
-                                _ => {
-                                    extension_entry(&mut channel.extensions, prefix, name).push(ext)
+                                ns => {
+                                    let consumed = options.on_unknown_element.as_ref().is_some_and(
+                                        |handler| handler.call(ns.unwrap_or(""), &ext),
+                                    );
+                                    if !consumed {
+                                        extension_entry(&mut channel.extensions, prefix, name)
+                                            .push(ext);
+                                    }
                                 }
                             }
                         } else {
@@ -1325,30 +2989,59 @@ impl Channel {
             buf.clear();
         }
 
-        // Process each of the namespaces we know
-        #[cfg(feature = "atom")]
-        if let Some(v) = extensions.remove(atom::NAMESPACE) {
-            channel.atom_ext = Some(atom::AtomExtension::from_map(v));
-        }
-        if let Some(v) = extensions.remove(itunes::NAMESPACE) {
-            channel.itunes_ext = Some(itunes::ITunesChannelExtension::from_map(v));
-        }
-        if let Some(v) = extensions.remove(dublincore::NAMESPACE) {
-            channel.dublin_core_ext = Some(dublincore::DublinCoreExtension::from_map(v));
-        }
-        if let Some(v) = extensions.remove(syndication::NAMESPACE) {
-            channel.syndication_ext = Some(syndication::SyndicationExtension::from_map(v));
-        }
-
-        Ok(channel)
+        Ok(())
     }
 }
 
-impl ToXml for Channel {
-    fn to_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), XmlError> {
+/// Returns the value read for `field`, or `Error::InvalidValue` if `ReadOptions::strict` is set
+/// and the value is not valid.
+fn checked(
+    options: &ReadOptions,
+    field: &'static str,
+    value: String,
+    valid: fn(&str) -> bool,
+) -> Result<String, Error> {
+    if options.strict && !valid(value.trim()) {
+        return Err(Error::InvalidValue { field, value });
+    }
+    Ok(value)
+}
+
+fn is_valid_ttl(ttl: &str) -> bool {
+    ttl.parse::<u32>().is_ok_and(|ttl| ttl > 0)
+}
+
+fn is_valid_hour(hour: &str) -> bool {
+    hour.parse::<u8>().is_ok_and(|hour| hour <= 23)
+}
+
+fn is_valid_day(day: &str) -> bool {
+    [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ]
+    .contains(&day)
+}
+
+impl Channel {
+    fn write_channel<W: Write>(
+        &self,
+        writer: &mut Writer<W>,
+        namespaces: &BTreeMap<String, String>,
+        options: &WriteOptions,
+    ) -> Result<(), XmlError> {
         let name = "channel";
 
-        writer.write_event(Event::Start(BytesStart::new(name)))?;
+        let mut element = BytesStart::new(name);
+        if let Some(xml_lang) = self.xml_lang.as_ref() {
+            element.push_attribute(("xml:lang", xml_lang.as_str()));
+        }
+        writer.write_event(Event::Start(element))?;
 
         writer.write_text_element("title", &self.title)?;
         writer.write_text_element("link", &self.link)?;
@@ -1371,11 +3064,11 @@ impl ToXml for Channel {
         }
 
         if let Some(pub_date) = self.pub_date.as_ref() {
-            writer.write_text_element("pubDate", pub_date)?;
+            writer.write_text_element("pubDate", options.format_date(pub_date))?;
         }
 
         if let Some(last_build_date) = self.last_build_date.as_ref() {
-            writer.write_text_element("lastBuildDate", last_build_date)?;
+            writer.write_text_element("lastBuildDate", options.format_date(last_build_date))?;
         }
 
         writer.write_objects(&self.categories)?;
@@ -1451,11 +3144,27 @@ impl ToXml for Channel {
             ext.to_xml(&self.namespaces, writer)?;
         }
 
-        writer.write_objects(&self.items)?;
+        let items: Box<dyn Iterator<Item = &Item>> = match options.item_order {
+            ItemOrder::AsStored => Box::new(self.items.iter()),
+            ItemOrder::Reverse => Box::new(self.items.iter().rev()),
+        };
+        for item in items {
+            item.write_item(writer, namespaces, options)?;
+        }
 
         writer.write_event(Event::End(BytesEnd::new(name)))?;
         Ok(())
     }
+}
+
+impl ToXml for Channel {
+    fn to_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), XmlError> {
+        self.write_channel(
+            writer,
+            &self.declared_namespaces(),
+            &WriteOptions::default(),
+        )
+    }
 
     fn used_namespaces(&self) -> BTreeMap<String, String> {
         let mut namespaces = BTreeMap::new();
@@ -1472,6 +3181,8 @@ impl ToXml for Channel {
         if let Some(ext) = self.atom_ext() {
             namespaces.extend(ext.used_namespaces());
         }
+        #[cfg(not(feature = "atom"))]
+        namespaces.extend(atom_namespace_declaration(&self.extensions));
         namespaces
     }
 }
@@ -1485,8 +3196,91 @@ impl FromStr for Channel {
     }
 }
 
+/// Creates a channel with default metadata that holds only the item.
+impl From<Item> for Channel {
+    fn from(item: Item) -> Channel {
+        Channel {
+            items: vec![item],
+            ..Default::default()
+        }
+    }
+}
+
+/// Creates a channel with default metadata that holds the items.
+impl FromIterator<Item> for Channel {
+    fn from_iter<I: IntoIterator<Item = Item>>(items: I) -> Channel {
+        Channel {
+            items: items.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+}
+
 #[cfg(feature = "builders")]
 impl ChannelBuilder {
+    /// Set the items of the channel from an iterator, replacing any previously set items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{ChannelBuilder, Item};
+    ///
+    /// let items = (0..3).map(|_| Item::default());
+    /// let channel = ChannelBuilder::default().items_iter(items).build();
+    /// assert_eq!(channel.items().len(), 3);
+    /// ```
+    pub fn items_iter<I>(&mut self, items: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        self.items = Some(items.into_iter().collect());
+        self
+    }
+
+    /// Set the title of the channel, or an empty title if `title` is `None`.
+    ///
+    /// The optional fields of the channel are set with their regular setters, which accept
+    /// `None` to clear a previously set value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::ChannelBuilder;
+    ///
+    /// let channel = ChannelBuilder::default()
+    ///     .title_or_default(None::<String>)
+    ///     .language("en-US".to_string())
+    ///     .language(None)
+    ///     .build();
+    /// assert_eq!(channel.title(), "");
+    /// assert_eq!(channel.language(), None);
+    /// ```
+    pub fn title_or_default<V>(&mut self, title: Option<V>) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        self.title = Some(title.map(Into::into).unwrap_or_default());
+        self
+    }
+
+    /// Set the link of the channel, or an empty link if `link` is `None`.
+    pub fn link_or_default<V>(&mut self, link: Option<V>) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        self.link = Some(link.map(Into::into).unwrap_or_default());
+        self
+    }
+
+    /// Set the description of the channel, or an empty description if `description` is `None`.
+    pub fn description_or_default<V>(&mut self, description: Option<V>) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        self.description = Some(description.map(Into::into).unwrap_or_default());
+        self
+    }
+
     /// Builds a new `Channel`.
     pub fn build(&self) -> Channel {
         self.build_impl().unwrap()
@@ -5,7 +5,9 @@
 // This program is free software; you can redistribute it and/or modify
 // it under the terms of the MIT License and/or Apache 2.0 License.
 
+use std::fmt;
 use std::io::{BufRead, Write};
+use std::num::ParseIntError;
 
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Error as XmlError;
@@ -106,6 +108,47 @@ impl Cloud {
         self.port = port.into();
     }
 
+    /// Return the port for this cloud as a number.
+    ///
+    /// Returns `None` if the port is empty and an error if it is not a valid port number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Cloud;
+    ///
+    /// let mut cloud = Cloud::default();
+    /// assert!(cloud.port_num().is_none());
+    ///
+    /// cloud.set_port("80");
+    /// assert_eq!(cloud.port_num(), Some(Ok(80)));
+    ///
+    /// cloud.set_port("70000");
+    /// assert!(cloud.port_num().unwrap().is_err());
+    /// ```
+    pub fn port_num(&self) -> Option<Result<u16, ParseIntError>> {
+        if self.port.is_empty() {
+            None
+        } else {
+            Some(self.port.parse())
+        }
+    }
+
+    /// Set the port for this cloud from a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Cloud;
+    ///
+    /// let mut cloud = Cloud::default();
+    /// cloud.set_port_num(80);
+    /// assert_eq!(cloud.port(), "80");
+    /// ```
+    pub fn set_port_num(&mut self, port: u16) {
+        self.port = port.to_string();
+    }
+
     /// Return the path for this cloud.
     ///
     /// # Examples
@@ -201,6 +244,82 @@ impl Cloud {
     {
         self.protocol = protocol.into();
     }
+
+    /// Return the protocol for this cloud as a `CloudProtocol`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Cloud, CloudProtocol};
+    ///
+    /// let mut cloud = Cloud::default();
+    /// cloud.set_protocol("soap");
+    /// assert_eq!(cloud.protocol_parsed(), CloudProtocol::Soap);
+    ///
+    /// cloud.set_protocol("xmlrpc");
+    /// assert_eq!(cloud.protocol_parsed(), CloudProtocol::Other("xmlrpc".to_string()));
+    /// ```
+    pub fn protocol_parsed(&self) -> CloudProtocol {
+        CloudProtocol::from(self.protocol.as_str())
+    }
+
+    /// Set the protocol for this cloud from a `CloudProtocol`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Cloud, CloudProtocol};
+    ///
+    /// let mut cloud = Cloud::default();
+    /// cloud.set_protocol_parsed(CloudProtocol::HttpPost);
+    /// assert_eq!(cloud.protocol(), "http-post");
+    /// ```
+    pub fn set_protocol_parsed(&mut self, protocol: CloudProtocol) {
+        self.protocol = protocol.to_string();
+    }
+}
+
+/// The protocol of a cloud.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CloudProtocol {
+    /// XML-RPC, written as `xml-rpc`.
+    XmlRpc,
+    /// SOAP, written as `soap`.
+    Soap,
+    /// HTTP POST, written as `http-post`.
+    HttpPost,
+    /// Any other protocol, which RSS does not define.
+    Other(String),
+}
+
+impl CloudProtocol {
+    /// Return the name of this protocol as written in the `protocol` attribute.
+    pub fn as_str(&self) -> &str {
+        match self {
+            CloudProtocol::XmlRpc => "xml-rpc",
+            CloudProtocol::Soap => "soap",
+            CloudProtocol::HttpPost => "http-post",
+            CloudProtocol::Other(protocol) => protocol,
+        }
+    }
+}
+
+impl From<&str> for CloudProtocol {
+    fn from(s: &str) -> Self {
+        match s {
+            "xml-rpc" => CloudProtocol::XmlRpc,
+            "soap" => CloudProtocol::Soap,
+            "http-post" => CloudProtocol::HttpPost,
+            _ => CloudProtocol::Other(s.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for CloudProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl Cloud {
@@ -0,0 +1,135 @@
+// This file is part of rss.
+//
+// Copyright © 2015-2021 The rust-syndication Developers
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the MIT License and/or Apache 2.0 License.
+
+use chrono::{DateTime, FixedOffset, ParseError};
+
+/// Named time zones and the offsets they are replaced with before parsing.
+const ZONES: [(&str, &str); 19] = [
+    ("GMT", "+0000"),
+    ("UT", "+0000"),
+    ("UTC", "+0000"),
+    ("Z", "+0000"),
+    ("EST", "-0500"),
+    ("EDT", "-0400"),
+    ("CST", "-0600"),
+    ("CDT", "-0500"),
+    ("MST", "-0700"),
+    ("MDT", "-0600"),
+    ("PST", "-0800"),
+    ("PDT", "-0700"),
+    ("BST", "+0100"),
+    ("WET", "+0000"),
+    ("WEST", "+0100"),
+    ("CET", "+0100"),
+    ("CEST", "+0200"),
+    ("EET", "+0200"),
+    ("EEST", "+0300"),
+];
+
+fn zone_offset(zone: &str) -> Option<&'static str> {
+    if let Some((_, offset)) = ZONES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(zone))
+    {
+        return Some(offset);
+    }
+    match zone.as_bytes() {
+        [c] if c.is_ascii_alphabetic() && !c.eq_ignore_ascii_case(&b'j') => Some("-0000"),
+        _ => None,
+    }
+}
+
+/// Parse an RFC 822 date, accepting the named time zones that old feeds still use.
+///
+/// Named zones are replaced with numeric offsets before the date is parsed as RFC 2822. Zone
+/// names are matched case-insensitively.
+///
+/// | Zone | Offset | | Zone | Offset |
+/// |------|--------|-|------|--------|
+/// | `GMT`, `UT`, `UTC`, `Z` | `+0000` | | `BST` | `+0100` |
+/// | `EST` | `-0500` | | `EDT` | `-0400` |
+/// | `CST` | `-0600` | | `CDT` | `-0500` |
+/// | `MST` | `-0700` | | `MDT` | `-0600` |
+/// | `PST` | `-0800` | | `PDT` | `-0700` |
+/// | `WET` | `+0000` | | `WEST` | `+0100` |
+/// | `CET` | `+0100` | | `CEST` | `+0200` |
+/// | `EET` | `+0200` | | `EEST` | `+0300` |
+///
+/// Single-letter military zones other than `Z` are read as `-0000` as recommended by RFC 2822,
+/// because RFC 822 defined their signs incorrectly.
+///
+/// # Examples
+///
+/// ```
+/// use rss::validation::parse_rfc822_date;
+///
+/// let date = parse_rfc822_date("Sun, 01 Jan 2017 12:00:00 EST").unwrap();
+/// assert_eq!(date.to_rfc3339(), "2017-01-01T12:00:00-05:00");
+/// ```
+pub fn parse_rfc822_date(value: &str) -> Result<DateTime<FixedOffset>, ParseError> {
+    let value = value.trim();
+    if let Some((date, zone)) = value.rsplit_once(char::is_whitespace) {
+        if let Some(offset) = zone_offset(zone) {
+            return DateTime::parse_from_rfc2822(&format!("{} {}", date.trim_end(), offset));
+        }
+    }
+    DateTime::parse_from_rfc2822(value)
+}
+
+/// Parse a date in either RFC 822 or RFC 3339 format, as used by the RSS and the Atom and
+/// Dublin Core date elements respectively.
+pub(crate) fn parse_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    parse_rfc822_date(value)
+        .or_else(|_| DateTime::parse_from_rfc3339(value.trim()))
+        .ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn named_zones() {
+        let parse = |value: &str| parse_rfc822_date(value).unwrap().to_rfc3339();
+        assert_eq!(
+            parse("Sun, 01 Jan 2017 12:00:00 EST"),
+            "2017-01-01T12:00:00-05:00"
+        );
+        assert_eq!(
+            parse("Sun, 01 Jan 2017 12:00:00 UTC"),
+            "2017-01-01T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse("Sun, 01 Jan 2017 12:00:00 cest"),
+            "2017-01-01T12:00:00+02:00"
+        );
+        assert_eq!(
+            parse("Sun, 01 Jan 2017 12:00:00 A"),
+            "2017-01-01T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse("Sun, 01 Jan 2017 12:00:00 +0100"),
+            "2017-01-01T12:00:00+01:00"
+        );
+        assert!(parse_rfc822_date("Sun, 01 Jan 2017 12:00:00 XYZ").is_err());
+        assert!(parse_rfc822_date("not a date").is_err());
+    }
+
+    #[test]
+    fn rfc822_or_rfc3339() {
+        let parse = |value: &str| parse_date(value).map(|date| date.to_rfc3339());
+        assert_eq!(
+            parse("Sun, 01 Jan 2017 12:00:00 GMT").as_deref(),
+            Some("2017-01-01T12:00:00+00:00")
+        );
+        assert_eq!(
+            parse(" 2017-01-01T12:00:00+02:00 ").as_deref(),
+            Some("2017-01-01T12:00:00+02:00")
+        );
+        assert_eq!(parse("2017-01-01"), None);
+    }
+}
@@ -0,0 +1,117 @@
+// This file is part of rss.
+//
+// Copyright © 2015-2021 The rust-syndication Developers
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the MIT License and/or Apache 2.0 License.
+
+use std::io::BufRead;
+
+use quick_xml::events::BytesDecl;
+use quick_xml::Reader;
+
+use crate::error::Error;
+use crate::util::decode;
+
+/// Represents the `<?xml ... ?>` declaration of a document.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlDeclaration {
+    /// The XML version.
+    pub version: String,
+    /// The declared encoding.
+    pub encoding: Option<String>,
+    /// The declared `standalone` value.
+    pub standalone: Option<String>,
+}
+
+impl Default for XmlDeclaration {
+    fn default() -> Self {
+        XmlDeclaration {
+            version: "1.0".to_string(),
+            encoding: Some("utf-8".to_string()),
+            standalone: None,
+        }
+    }
+}
+
+impl XmlDeclaration {
+    /// Return the XML version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::XmlDeclaration;
+    ///
+    /// let declaration = XmlDeclaration::default();
+    /// assert_eq!(declaration.version(), "1.0");
+    /// ```
+    pub fn version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    /// Return the declared encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::XmlDeclaration;
+    ///
+    /// let declaration = XmlDeclaration::default();
+    /// assert_eq!(declaration.encoding(), Some("utf-8"));
+    /// ```
+    pub fn encoding(&self) -> Option<&str> {
+        self.encoding.as_deref()
+    }
+
+    /// Return the declared `standalone` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::XmlDeclaration;
+    ///
+    /// let declaration = XmlDeclaration::default();
+    /// assert_eq!(declaration.standalone(), None);
+    /// ```
+    pub fn standalone(&self) -> Option<&str> {
+        self.standalone.as_deref()
+    }
+}
+
+impl XmlDeclaration {
+    /// Builds an XmlDeclaration from a parsed declaration event.
+    pub(crate) fn from_xml<R: BufRead>(
+        reader: &Reader<R>,
+        decl: &BytesDecl<'_>,
+    ) -> Result<Self, Error> {
+        let version = decode(&decl.version()?, reader)?.into_owned();
+        let encoding = match decl.encoding() {
+            Some(encoding) => Some(decode(&encoding?, reader)?.into_owned()),
+            None => None,
+        };
+        let standalone = match decl.standalone() {
+            Some(standalone) => Some(decode(&standalone?, reader)?.into_owned()),
+            None => None,
+        };
+
+        Ok(XmlDeclaration {
+            version,
+            encoding,
+            standalone,
+        })
+    }
+
+    /// Returns the declaration to write. The written document is always UTF-8, so a declared
+    /// encoding that does not name UTF-8 is replaced.
+    pub(crate) fn to_bytes_decl(&self) -> BytesDecl<'_> {
+        let encoding = self.encoding().map(|encoding| {
+            if encoding.eq_ignore_ascii_case("utf-8") || encoding.eq_ignore_ascii_case("utf8") {
+                encoding
+            } else {
+                "utf-8"
+            }
+        });
+        BytesDecl::new(self.version(), encoding, self.standalone())
+    }
+}
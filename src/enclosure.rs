@@ -6,6 +6,7 @@
 // it under the terms of the MIT License and/or Apache 2.0 License.
 
 use std::io::{BufRead, Write};
+use std::num::ParseIntError;
 
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::Error as XmlError;
@@ -31,7 +32,8 @@ use crate::util::{attr_value, decode, skip};
 pub struct Enclosure {
     /// The URL of the enclosure.
     pub url: String,
-    /// The length of the enclosure in bytes.
+    /// The length of the enclosure in bytes. A length of `0` is permitted for live or
+    /// unknown-size media.
     pub length: String,
     /// The MIME type of the enclosure.
     pub mime_type: String,
@@ -70,6 +72,28 @@ impl Enclosure {
         self.url = url.into();
     }
 
+    /// Resolve the URL of this enclosure against a base URL, such as the URL the feed was
+    /// fetched from. Absolute URLs are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Enclosure;
+    /// use url::Url;
+    ///
+    /// let base = Url::parse("http://example.com/podcast/feed.xml").unwrap();
+    /// let mut enclosure = Enclosure::default();
+    /// enclosure.set_url("episodes/1.mp3");
+    /// assert_eq!(
+    ///     enclosure.resolved_url(&base).unwrap().as_str(),
+    ///     "http://example.com/podcast/episodes/1.mp3"
+    /// );
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn resolved_url(&self, base: &url::Url) -> Result<url::Url, url::ParseError> {
+        base.join(self.url())
+    }
+
     /// Return the content length of this enclosure.
     ///
     /// # Examples
@@ -85,6 +109,42 @@ impl Enclosure {
         self.length.as_str()
     }
 
+    /// Parse the content length of this enclosure as a number of bytes.
+    ///
+    /// Some feeds format the length for humans, so thousands separators (`4,992,349`) and a
+    /// fractional part of zeros (`4992349.0`) are removed before parsing. Commas anywhere else
+    /// are an error. The raw value returned by [`length`](Enclosure::length) is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Enclosure;
+    ///
+    /// let mut enclosure = Enclosure::default();
+    /// enclosure.set_length("4,992,349");
+    /// assert_eq!(enclosure.length_bytes(), Ok(4992349));
+    /// assert_eq!(enclosure.length(), "4,992,349");
+    /// enclosure.set_length("4992349.0");
+    /// assert_eq!(enclosure.length_bytes(), Ok(4992349));
+    /// enclosure.set_length("4992349.5");
+    /// assert!(enclosure.length_bytes().is_err());
+    /// enclosure.set_length("1,2,3");
+    /// assert!(enclosure.length_bytes().is_err());
+    /// ```
+    pub fn length_bytes(&self) -> Result<u64, ParseIntError> {
+        let length = self.length.trim();
+        let length = match length.split_once('.') {
+            Some((whole, fraction)) if fraction.bytes().all(|b| b == b'0') => whole,
+            _ => length,
+        };
+        if has_thousands_separators(length) {
+            length.replace(',', "").parse()
+        } else {
+            // a misplaced comma is left in and fails to parse
+            length.parse()
+        }
+    }
+
     /// Set the content length of this enclosure.
     ///
     /// # Examples
@@ -133,6 +193,127 @@ impl Enclosure {
     {
         self.mime_type = mime_type.into();
     }
+
+    /// Infer the MIME type of this enclosure from the extension of its URL.
+    ///
+    /// Returns `None` if the MIME type is set, the last segment of the URL's path has no
+    /// extension, or the extension is not one of `mp3`, `m4a`, `mp4`, `ogg`, `opus`, `aac`,
+    /// `flac`, `m4v` and `webm`. The stored MIME type is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Enclosure;
+    ///
+    /// let mut enclosure = Enclosure::default();
+    /// enclosure.set_url("http://example.com/media.mp3?download=1");
+    /// assert_eq!(enclosure.inferred_mime_type(), Some("audio/mpeg"));
+    ///
+    /// enclosure.set_mime_type("audio/x-mpeg");
+    /// assert_eq!(enclosure.inferred_mime_type(), None);
+    /// ```
+    pub fn inferred_mime_type(&self) -> Option<&'static str> {
+        if !self.mime_type.trim().is_empty() {
+            return None;
+        }
+        mime_type_from_url(&self.url)
+    }
+}
+
+/// The metadata of a remote file, as reported by the headers of a `HEAD` response.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EnclosureMetadata {
+    /// The value of the `Content-Length` header.
+    pub content_length: Option<u64>,
+    /// The value of the `Content-Type` header.
+    pub content_type: Option<String>,
+}
+
+/// A client that requests the metadata of enclosures over HTTP.
+///
+/// This crate does not depend on an HTTP client. Implement this trait with the client of your
+/// choice to use [`Item::fill_enclosure_metadata`](crate::Item::fill_enclosure_metadata).
+///
+/// # Examples
+///
+/// ```
+/// use rss::{EnclosureMetadata, HeadClient};
+///
+/// struct Fixed;
+///
+/// impl HeadClient for Fixed {
+///     type Error = std::io::Error;
+///
+///     fn head(&self, _url: &str) -> Result<EnclosureMetadata, Self::Error> {
+///         Ok(EnclosureMetadata {
+///             content_length: Some(4096),
+///             content_type: Some("audio/mpeg".to_string()),
+///         })
+///     }
+/// }
+/// ```
+pub trait HeadClient {
+    /// The error returned when a request fails.
+    type Error;
+
+    /// Sends a `HEAD` request for `url` and returns the metadata from the response headers.
+    fn head(&self, url: &str) -> Result<EnclosureMetadata, Self::Error>;
+}
+
+impl Enclosure {
+    /// Creates an enclosure for `url` from the metadata of the remote file.
+    ///
+    /// Parameters of the content type, such as a charset, are removed. Missing metadata falls
+    /// back to a length of `0` and the MIME type inferred from the URL's file extension.
+    pub(crate) fn from_metadata(url: String, metadata: EnclosureMetadata) -> Self {
+        let mime_type = metadata
+            .content_type
+            .as_deref()
+            .and_then(|content_type| content_type.split(';').next())
+            .map(str::trim)
+            .filter(|mime_type| !mime_type.is_empty())
+            .or_else(|| mime_type_from_url(&url))
+            .unwrap_or_default()
+            .to_string();
+        Enclosure {
+            length: metadata.content_length.unwrap_or(0).to_string(),
+            url,
+            mime_type,
+        }
+    }
+}
+
+/// Returns true if `value` groups its digits in threes separated by commas, as in `4,992,349`.
+fn has_thousands_separators(value: &str) -> bool {
+    let mut groups = value.split(',');
+    let first = groups.next().unwrap_or_default();
+    value.contains(',') && (1..=3).contains(&first.len()) && groups.all(|group| group.len() == 3)
+}
+
+/// Returns the MIME type for the file extension of a URL, ignoring its query and fragment.
+///
+/// Only the last segment of the path is considered, so a URL without a path has no extension.
+fn mime_type_from_url(url: &str) -> Option<&'static str> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let path = match path.split_once("://") {
+        Some((_, rest)) => rest.split_once('/').map_or("", |(_, path)| path),
+        None => path,
+    };
+    let file_name = path.rsplit('/').next().unwrap_or_default();
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let mime_type = match extension.to_ascii_lowercase().as_str() {
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "mp4" => "video/mp4",
+        "ogg" => "audio/ogg",
+        "opus" => "audio/opus",
+        "aac" => "audio/aac",
+        "flac" => "audio/flac",
+        "m4v" => "video/x-m4v",
+        "webm" => "video/webm",
+        _ => return None,
+    };
+    Some(mime_type)
 }
 
 impl Enclosure {
@@ -172,6 +353,55 @@ impl ToXml for Enclosure {
 
 #[cfg(feature = "builders")]
 impl EnclosureBuilder {
+    /// Creates a builder for an enclosure with the given URL, length in bytes and MIME type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::EnclosureBuilder;
+    ///
+    /// let enclosure =
+    ///     EnclosureBuilder::from_file_meta("http://example.com/1.mp3", 4096, "audio/mpeg").build();
+    /// assert_eq!(enclosure.url(), "http://example.com/1.mp3");
+    /// assert_eq!(enclosure.length(), "4096");
+    /// assert_eq!(enclosure.mime_type(), "audio/mpeg");
+    /// ```
+    pub fn from_file_meta<V>(url: V, length: u64, mime_type: &str) -> Self
+    where
+        V: Into<String>,
+    {
+        let mut builder = EnclosureBuilder::default();
+        builder
+            .url(url)
+            .length(length.to_string())
+            .mime_type(mime_type);
+        builder
+    }
+
+    /// Set the URL of the enclosure and, if its file extension is known, the MIME type. The
+    /// extensions are those recognized by `Enclosure::inferred_mime_type`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::EnclosureBuilder;
+    ///
+    /// let enclosure = EnclosureBuilder::default()
+    ///     .url_with_inferred_type("http://example.com/1.m4a")
+    ///     .build();
+    /// assert_eq!(enclosure.mime_type(), "audio/mp4");
+    /// ```
+    pub fn url_with_inferred_type<V>(&mut self, url: V) -> &mut Self
+    where
+        V: Into<String>,
+    {
+        let url = url.into();
+        if let Some(mime_type) = mime_type_from_url(&url) {
+            self.mime_type(mime_type);
+        }
+        self.url(url)
+    }
+
     /// Builds a new `Enclosure`.
     pub fn build(&self) -> Enclosure {
         self.build_impl().unwrap()
@@ -13,8 +13,14 @@ use std::sync::Arc;
 
 use quick_xml::Error as XmlError;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 /// Errors that occur during parsing.
+///
+/// Cloning preserves the full error, including the underlying XML error; I/O errors are
+/// reference counted and shared between clones.
+///
+/// New variants may be added in minor releases, so matches on this type need a wildcard arm.
+#[non_exhaustive]
 pub enum Error {
     /// An error while converting bytes to UTF8.
     Utf8(Utf8Error),
@@ -22,8 +28,20 @@ pub enum Error {
     Xml(XmlError),
     /// The input didn't begin with an opening `<rss>` tag.
     InvalidStartTag,
+    /// The input is an HTML document rather than a feed.
+    NotAFeed,
+    /// The input declared an encoding that cannot be decoded.
+    UnsupportedEncoding(String),
     /// The end of the input was reached without finding a complete channel element.
     Eof,
+    /// A field had an out-of-range or malformed value while reading in strict mode, or a value
+    /// that cannot be written.
+    InvalidValue {
+        /// The name of the element.
+        field: &'static str,
+        /// The value that was read.
+        value: String,
+    },
 }
 
 impl StdError for Error {
@@ -31,7 +49,11 @@ impl StdError for Error {
         match *self {
             Error::Utf8(ref err) => Some(err),
             Error::Xml(ref err) => Some(err),
-            Error::InvalidStartTag | Error::Eof => None,
+            Error::InvalidStartTag
+            | Error::NotAFeed
+            | Error::UnsupportedEncoding(_)
+            | Error::Eof
+            | Error::InvalidValue { .. } => None,
         }
     }
 }
@@ -42,7 +64,14 @@ impl fmt::Display for Error {
             Error::Utf8(ref err) => fmt::Display::fmt(err, f),
             Error::Xml(ref err) => fmt::Display::fmt(err, f),
             Error::InvalidStartTag => write!(f, "the input did not begin with an rss tag"),
+            Error::NotAFeed => write!(f, "the input is an HTML document, not a feed"),
+            Error::UnsupportedEncoding(ref encoding) => {
+                write!(f, "unsupported encoding `{}`", encoding)
+            }
             Error::Eof => write!(f, "reached end of input without finding a complete channel"),
+            Error::InvalidValue { field, ref value } => {
+                write!(f, "invalid value `{}` for `{}`", value, field)
+            }
         }
     }
 }
@@ -59,6 +88,12 @@ impl From<quick_xml::encoding::EncodingError> for Error {
     }
 }
 
+impl From<quick_xml::events::attributes::AttrError> for Error {
+    fn from(err: quick_xml::events::attributes::AttrError) -> Error {
+        Error::Xml(XmlError::InvalidAttr(err))
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::Xml(XmlError::Io(Arc::new(err)))
@@ -80,4 +115,12 @@ mod test {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<Error>();
     }
+
+    #[test]
+    fn error_clone() {
+        let err = Error::from(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated"));
+        let cloned = err.clone();
+        assert_eq!(err.to_string(), cloned.to_string());
+        assert!(cloned.source().is_some());
+    }
 }
@@ -7,21 +7,27 @@
 
 use std::collections::BTreeMap;
 use std::io::Write;
+use std::ops::Deref;
 
-pub use atom_syndication::Link;
-use quick_xml::events::{BytesStart, Event};
+pub use atom_syndication::{Category, Link, Person};
+use quick_xml::events::{BytesEnd, BytesStart, Event};
 use quick_xml::Error as XmlError;
 use quick_xml::Writer;
 
 use crate::extension::Extension;
-use crate::toxml::ToXml;
+use crate::toxml::{ToXml, WriterExt};
 
 /// The Atom XML namespace.
 pub const NAMESPACE: &str = "http://www.w3.org/2005/Atom";
 
 /// An Atom element extension.
+///
+/// New Atom elements may be added in the future, so this cannot be built with a struct
+/// literal outside of this crate. Use `AtomExtension::default()` and the setters, or
+/// `AtomExtensionBuilder`.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 #[cfg_attr(feature = "builders", derive(Builder))]
 #[cfg_attr(
     feature = "builders",
@@ -33,22 +39,202 @@ pub const NAMESPACE: &str = "http://www.w3.org/2005/Atom";
 )]
 pub struct AtomExtension {
     /// Links
-    #[cfg_attr(feature = "builders", builder(setter(each = "link")))]
-    pub links: Vec<Link>,
+    #[cfg_attr(
+        feature = "builders",
+        builder(setter(each(name = "link", into = true)))
+    )]
+    pub links: Vec<AtomLink>,
+    /// Categories
+    #[cfg_attr(feature = "builders", builder(setter(each = "category")))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub categories: Vec<Category>,
+    /// Authors
+    #[cfg_attr(feature = "builders", builder(setter(each = "author")))]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub authors: Vec<Person>,
+    /// The `atom:published` date, as an RFC 3339 timestamp.
+    pub published: Option<String>,
+    /// The `atom:id` identifier.
+    pub id: Option<String>,
 }
 
 impl AtomExtension {
     /// Retrieve links
-    pub fn links(&self) -> &[Link] {
+    pub fn links(&self) -> &[AtomLink] {
         &self.links
     }
 
     /// Set links
-    pub fn set_links<V>(&mut self, links: V)
+    pub fn set_links<V, L>(&mut self, links: V)
     where
-        V: Into<Vec<Link>>,
+        V: IntoIterator<Item = L>,
+        L: Into<AtomLink>,
     {
-        self.links = links.into();
+        self.links = links.into_iter().map(Into::into).collect();
+    }
+
+    /// Retrieve the targets of the [RFC 5005](https://www.rfc-editor.org/rfc/rfc5005) paging
+    /// links, `rel="next"`, `rel="prev"` (or `rel="previous"`), `rel="first"` and
+    /// `rel="last"`. The first link of each relation is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::atom::{AtomExtension, Link};
+    ///
+    /// let mut next = Link::default();
+    /// next.set_rel("next");
+    /// next.set_href("http://example.com/feed?page=3");
+    ///
+    /// let mut extension = AtomExtension::default();
+    /// extension.set_links(vec![next]);
+    /// let pagination = extension.pagination_links();
+    /// assert_eq!(pagination.next, Some("http://example.com/feed?page=3"));
+    /// assert_eq!(pagination.prev, None);
+    /// ```
+    pub fn pagination_links(&self) -> Paginated<'_> {
+        let href = |rels: &[&str]| {
+            self.links
+                .iter()
+                .find(|link| rels.contains(&link.rel()))
+                .map(|link| link.href())
+        };
+        Paginated {
+            next: href(&["next"]),
+            prev: href(&["prev", "previous"]),
+            first: href(&["first"]),
+            last: href(&["last"]),
+        }
+    }
+
+    /// Retrieve categories
+    pub fn categories(&self) -> &[Category] {
+        &self.categories
+    }
+
+    /// Set categories
+    pub fn set_categories<V>(&mut self, categories: V)
+    where
+        V: Into<Vec<Category>>,
+    {
+        self.categories = categories.into();
+    }
+
+    /// Retrieve authors
+    pub fn authors(&self) -> &[Person] {
+        &self.authors
+    }
+
+    /// Set authors
+    pub fn set_authors<V>(&mut self, authors: V)
+    where
+        V: Into<Vec<Person>>,
+    {
+        self.authors = authors.into();
+    }
+
+    /// Retrieve the `atom:published` date
+    pub fn published(&self) -> Option<&str> {
+        self.published.as_deref()
+    }
+
+    /// Set the `atom:published` date
+    pub fn set_published<V>(&mut self, published: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.published = published.into();
+    }
+
+    /// Retrieve the `atom:id` identifier
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Set the `atom:id` identifier
+    pub fn set_id<V>(&mut self, id: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.id = id.into();
+    }
+}
+
+/// The paging links of a feed, as returned by `AtomExtension::pagination_links`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Paginated<'a> {
+    /// The URL of the next page.
+    pub next: Option<&'a str>,
+    /// The URL of the previous page.
+    pub prev: Option<&'a str>,
+    /// The URL of the first page.
+    pub first: Option<&'a str>,
+    /// The URL of the last page.
+    pub last: Option<&'a str>,
+}
+
+/// An `atom:link` together with the attributes that `Link` has no field for.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AtomLink {
+    /// The link.
+    pub link: Link,
+    /// Other attributes of the link, such as a non-standard `priority`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extra_attrs: BTreeMap<String, String>,
+}
+
+impl AtomLink {
+    /// Retrieve the link
+    pub fn link(&self) -> &Link {
+        &self.link
+    }
+
+    /// Retrieve the attributes of the link that `Link` has no field for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = r#"<rss xmlns:atom="http://www.w3.org/2005/Atom"><channel>
+    ///     <atom:link href="http://example.com/feed" rel="self" priority="1"/>
+    /// </channel></rss>"#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    /// let link = &channel.atom_ext().unwrap().links()[0];
+    /// assert_eq!(link.extra_attrs()["priority"], "1");
+    /// ```
+    pub fn extra_attrs(&self) -> &BTreeMap<String, String> {
+        &self.extra_attrs
+    }
+
+    /// Retrieve a mutable reference to the attributes of the link that `Link` has no field for
+    pub fn extra_attrs_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.extra_attrs
+    }
+}
+
+impl From<Link> for AtomLink {
+    fn from(link: Link) -> Self {
+        AtomLink {
+            link,
+            extra_attrs: BTreeMap::new(),
+        }
+    }
+}
+
+impl Deref for AtomLink {
+    type Target = Link;
+
+    fn deref(&self) -> &Link {
+        &self.link
+    }
+}
+
+/// A link without extra attributes equals the `Link` it wraps.
+impl PartialEq<Link> for AtomLink {
+    fn eq(&self, other: &Link) -> bool {
+        self.extra_attrs.is_empty() && self.link == *other
     }
 }
 
@@ -60,7 +246,7 @@ impl AtomExtension {
             .unwrap_or_default()
             .into_iter()
             .filter_map(|mut link_ext| {
-                Some(Link {
+                let link = Link {
                     href: link_ext.attrs.remove("href")?,
                     rel: link_ext
                         .attrs
@@ -70,11 +256,65 @@ impl AtomExtension {
                     mime_type: link_ext.attrs.remove("type"),
                     title: link_ext.attrs.remove("title"),
                     length: link_ext.attrs.remove("length"),
+                };
+                Some(AtomLink {
+                    link,
+                    extra_attrs: link_ext.attrs,
+                })
+            })
+            .collect();
+
+        let categories = map
+            .remove("category")
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|mut category_ext| {
+                Some(Category {
+                    term: category_ext.attrs.remove("term")?,
+                    scheme: category_ext.attrs.remove("scheme"),
+                    label: category_ext.attrs.remove("label"),
                 })
             })
             .collect();
 
-        Self { links }
+        let authors = map
+            .remove("author")
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mut author_ext| {
+                let mut child_value = |name: &str| {
+                    author_ext
+                        .children
+                        .remove(name)
+                        .and_then(|children| children.into_iter().next())
+                        .and_then(|child| child.value)
+                };
+                Person {
+                    name: child_value("name").unwrap_or_default(),
+                    email: child_value("email"),
+                    uri: child_value("uri"),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        let published = map
+            .remove("published")
+            .and_then(|published| published.into_iter().next())
+            .and_then(|published| published.value);
+
+        let id = map
+            .remove("id")
+            .and_then(|id| id.into_iter().next())
+            .and_then(|id| id.value);
+
+        Self {
+            links,
+            categories,
+            authors,
+            published,
+            id,
+        }
     }
 }
 
@@ -101,8 +341,53 @@ impl ToXml for AtomExtension {
                 element.push_attribute(("length", &**length));
             }
 
+            element.extend_attributes(
+                link.extra_attrs
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str())),
+            );
+
             writer.write_event(Event::Empty(element))?;
         }
+
+        for category in &self.categories {
+            let mut element = BytesStart::new("atom:category");
+            element.push_attribute(("term", &*category.term));
+
+            if let Some(ref scheme) = category.scheme {
+                element.push_attribute(("scheme", &**scheme));
+            }
+
+            if let Some(ref label) = category.label {
+                element.push_attribute(("label", &**label));
+            }
+
+            writer.write_event(Event::Empty(element))?;
+        }
+
+        for author in &self.authors {
+            let name = "atom:author";
+            writer.write_event(Event::Start(BytesStart::new(name)))?;
+            writer.write_text_element("atom:name", &author.name)?;
+
+            if let Some(ref email) = author.email {
+                writer.write_text_element("atom:email", email)?;
+            }
+
+            if let Some(ref uri) = author.uri {
+                writer.write_text_element("atom:uri", uri)?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new(name)))?;
+        }
+
+        if let Some(ref published) = self.published {
+            writer.write_text_element("atom:published", published)?;
+        }
+
+        if let Some(ref id) = self.id {
+            writer.write_text_element("atom:id", id)?;
+        }
         Ok(())
     }
 
@@ -151,13 +436,16 @@ mod tests {
                         rel: "self".to_string(),
                         href: "http://example.com/feed".to_string(),
                         ..Default::default()
-                    },
+                    }
+                    .into(),
                     Link {
                         rel: "alternate".to_string(),
                         href: "http://example.com".to_string(),
                         ..Default::default()
                     }
-                ]
+                    .into()
+                ],
+                ..Default::default()
             }
         );
     }
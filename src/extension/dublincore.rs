@@ -86,6 +86,21 @@ impl DublinCoreExtension {
         &self.contributors
     }
 
+    /// Return the first of the contributors to the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_contributors(vec!["Alice".to_string(), "Bob".to_string()]);
+    /// assert_eq!(dublin_core.contributor(), Some("Alice"));
+    /// ```
+    pub fn contributor(&self) -> Option<&str> {
+        self.contributors.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the contributors to the resource.
     pub fn contributors_mut(&mut self) -> &mut [String] {
         &mut self.contributors
@@ -105,6 +120,21 @@ impl DublinCoreExtension {
         &self.coverages
     }
 
+    /// Return the first of the spatial or temporal topics of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_coverages(vec!["Europe".to_string(), "Asia".to_string()]);
+    /// assert_eq!(dublin_core.coverage(), Some("Europe"));
+    /// ```
+    pub fn coverage(&self) -> Option<&str> {
+        self.coverages.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the spatial or temporal topics of the resource, the spatial
     /// applicabilities of the resource, or the jurisdictions under which the resource is relevant.
     pub fn coverages_mut(&mut self) -> &mut [String] {
@@ -125,6 +155,21 @@ impl DublinCoreExtension {
         &self.creators
     }
 
+    /// Return the first of the creators of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_creators(vec!["Alice".to_string(), "Bob".to_string()]);
+    /// assert_eq!(dublin_core.creator(), Some("Alice"));
+    /// ```
+    pub fn creator(&self) -> Option<&str> {
+        self.creators.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the creators of the resource.
     pub fn creators_mut(&mut self) -> &mut [String] {
         &mut self.creators
@@ -143,6 +188,21 @@ impl DublinCoreExtension {
         &self.dates
     }
 
+    /// Return the first of the times associated with the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_dates(vec!["2017-01-01".to_string(), "2017-01-02".to_string()]);
+    /// assert_eq!(dublin_core.date(), Some("2017-01-01"));
+    /// ```
+    pub fn date(&self) -> Option<&str> {
+        self.dates.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the times associated with the resource.
     pub fn dates_mut(&mut self) -> &mut [String] {
         &mut self.dates
@@ -161,6 +221,21 @@ impl DublinCoreExtension {
         &self.descriptions
     }
 
+    /// Return the first of the descriptions of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_descriptions(vec!["First".to_string(), "Second".to_string()]);
+    /// assert_eq!(dublin_core.description(), Some("First"));
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.descriptions.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the descriptions of the resource.
     pub fn descriptions_mut(&mut self) -> &mut [String] {
         &mut self.descriptions
@@ -179,6 +254,21 @@ impl DublinCoreExtension {
         &self.formats
     }
 
+    /// Return the first of the file formats, physical mediums, or dimensions of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_formats(vec!["text/html".to_string(), "text/plain".to_string()]);
+    /// assert_eq!(dublin_core.format(), Some("text/html"));
+    /// ```
+    pub fn format(&self) -> Option<&str> {
+        self.formats.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the file formats, physical mediums, or
     /// dimensions of the resource.
     pub fn formats_mut(&mut self) -> &mut [String] {
@@ -198,6 +288,21 @@ impl DublinCoreExtension {
         &self.identifiers
     }
 
+    /// Return the first of the identifiers of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_identifiers(vec!["urn:isbn:0451450523".to_string(), "urn:isbn:0451450524".to_string()]);
+    /// assert_eq!(dublin_core.identifier(), Some("urn:isbn:0451450523"));
+    /// ```
+    pub fn identifier(&self) -> Option<&str> {
+        self.identifiers.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the identifiers of the resource.
     pub fn identifiers_mut(&mut self) -> &mut [String] {
         &mut self.identifiers
@@ -216,6 +321,21 @@ impl DublinCoreExtension {
         &self.languages
     }
 
+    /// Return the first of the languages of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_languages(vec!["en".to_string(), "de".to_string()]);
+    /// assert_eq!(dublin_core.language(), Some("en"));
+    /// ```
+    pub fn language(&self) -> Option<&str> {
+        self.languages.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the languages of the resource.
     pub fn languages_mut(&mut self) -> &mut [String] {
         &mut self.languages
@@ -234,6 +354,21 @@ impl DublinCoreExtension {
         &self.publishers
     }
 
+    /// Return the first of the publishers of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_publishers(vec!["Example Press".to_string(), "Other Press".to_string()]);
+    /// assert_eq!(dublin_core.publisher(), Some("Example Press"));
+    /// ```
+    pub fn publisher(&self) -> Option<&str> {
+        self.publishers.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the publishers of the resource.
     pub fn publishers_mut(&mut self) -> &mut [String] {
         &mut self.publishers
@@ -252,6 +387,21 @@ impl DublinCoreExtension {
         &self.relations
     }
 
+    /// Return the first of the related resources.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_relations(vec!["http://example.com/1".to_string(), "http://example.com/2".to_string()]);
+    /// assert_eq!(dublin_core.relation(), Some("http://example.com/1"));
+    /// ```
+    pub fn relation(&self) -> Option<&str> {
+        self.relations.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the related resources.
     pub fn relations_mut(&mut self) -> &mut [String] {
         &mut self.relations
@@ -270,6 +420,23 @@ impl DublinCoreExtension {
         &self.rights
     }
 
+    /// Return the first statement of the rights held in and over the resource.
+    ///
+    /// `rights` already returns all of them, so this is named after a single right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_rights(vec!["Copyright 2017".to_string(), "CC BY 4.0".to_string()]);
+    /// assert_eq!(dublin_core.right(), Some("Copyright 2017"));
+    /// ```
+    pub fn right(&self) -> Option<&str> {
+        self.rights.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the information about rights held in and over
     /// the resource.
     pub fn rights_mut(&mut self) -> &mut [String] {
@@ -289,6 +456,21 @@ impl DublinCoreExtension {
         &self.sources
     }
 
+    /// Return the first of the sources of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_sources(vec!["http://example.com/1".to_string(), "http://example.com/2".to_string()]);
+    /// assert_eq!(dublin_core.source(), Some("http://example.com/1"));
+    /// ```
+    pub fn source(&self) -> Option<&str> {
+        self.sources.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the sources of the resource.
     pub fn sources_mut(&mut self) -> &mut [String] {
         &mut self.sources
@@ -307,6 +489,21 @@ impl DublinCoreExtension {
         &self.subjects
     }
 
+    /// Return the first of the topics of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_subjects(vec!["Science".to_string(), "History".to_string()]);
+    /// assert_eq!(dublin_core.subject(), Some("Science"));
+    /// ```
+    pub fn subject(&self) -> Option<&str> {
+        self.subjects.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the subjects of the resource.
     pub fn subjects_mut(&mut self) -> &mut [String] {
         &mut self.subjects
@@ -325,6 +522,21 @@ impl DublinCoreExtension {
         &self.titles
     }
 
+    /// Return the first of the titles of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_titles(vec!["First".to_string(), "Second".to_string()]);
+    /// assert_eq!(dublin_core.title(), Some("First"));
+    /// ```
+    pub fn title(&self) -> Option<&str> {
+        self.titles.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the titles of the resource.
     pub fn titles_mut(&mut self) -> &mut [String] {
         &mut self.titles
@@ -343,6 +555,23 @@ impl DublinCoreExtension {
         &self.types
     }
 
+    /// Return the first of the natures or genres of the resource.
+    ///
+    /// `type` is a keyword, so this is called as `r#type()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_types(vec!["Text".to_string(), "Image".to_string()]);
+    /// assert_eq!(dublin_core.r#type(), Some("Text"));
+    /// ```
+    pub fn r#type(&self) -> Option<&str> {
+        self.types.first().map(String::as_str)
+    }
+
     /// Return a mutable slice of the natures or genres of the resource.
     pub fn types_mut(&mut self) -> &mut [String] {
         &mut self.types
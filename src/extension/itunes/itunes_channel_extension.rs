@@ -12,7 +12,7 @@ use quick_xml::events::{BytesStart, Event};
 use quick_xml::Error as XmlError;
 use quick_xml::Writer;
 
-use super::{parse_categories, parse_image, parse_owner, NAMESPACE};
+use super::{parse_categories, parse_flag, parse_image, parse_owner, unknown_category, NAMESPACE};
 use crate::extension::itunes::{ITunesCategory, ITunesOwner};
 use crate::extension::util::remove_extension_value;
 use crate::extension::Extension;
@@ -134,6 +134,27 @@ impl ITunesChannelExtension {
         self.block = block.into();
     }
 
+    /// Return whether the podcast should be hidden from the iTunes Store.
+    ///
+    /// Returns `Some(true)` if the value is `Yes` or `true` (ignoring case), `Some(false)` for any
+    /// other value and `None` if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesChannelExtension;
+    ///
+    /// let mut extension = ITunesChannelExtension::default();
+    /// assert_eq!(extension.blocked(), None);
+    /// extension.set_block("yes".to_string());
+    /// assert_eq!(extension.blocked(), Some(true));
+    /// extension.set_block("No".to_string());
+    /// assert_eq!(extension.blocked(), Some(false));
+    /// ```
+    pub fn blocked(&self) -> Option<bool> {
+        self.block.as_deref().map(parse_flag)
+    }
+
     /// Return the iTunes categories that the podcast belongs to.
     ///
     /// # Examples
@@ -171,6 +192,49 @@ impl ITunesChannelExtension {
         self.categories = categories.into();
     }
 
+    /// Check the categories and subcategories against the categories accepted by Apple
+    /// Podcasts, returning the unknown ones.
+    ///
+    /// Names are compared exactly, as Apple does. Each unknown category is reported with its
+    /// subcategories joined with ` > `.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::{ITunesCategory, ITunesChannelExtension};
+    ///
+    /// let mut technology = ITunesCategory::default();
+    /// technology.set_text("Technology");
+    ///
+    /// let mut arts = ITunesCategory::default();
+    /// arts.set_text("Arts");
+    /// let mut painting = ITunesCategory::default();
+    /// painting.set_text("Painting");
+    /// arts.set_subcategory(Box::new(painting));
+    ///
+    /// let mut extension = ITunesChannelExtension::default();
+    /// extension.set_categories(vec![technology.clone()]);
+    /// assert!(extension.validate_categories().is_ok());
+    ///
+    /// extension.set_categories(vec![technology, arts]);
+    /// assert_eq!(
+    ///     extension.validate_categories(),
+    ///     Err(vec!["Arts > Painting".to_string()])
+    /// );
+    /// ```
+    pub fn validate_categories(&self) -> Result<(), Vec<String>> {
+        let unknown = self
+            .categories
+            .iter()
+            .filter_map(unknown_category)
+            .collect::<Vec<_>>();
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(unknown)
+        }
+    }
+
     /// Return the artwork URL for the podcast.
     ///
     /// # Examples
@@ -279,6 +343,27 @@ impl ITunesChannelExtension {
         self.complete = complete.into();
     }
 
+    /// Return whether the podcast is complete.
+    ///
+    /// Returns `Some(true)` if the value is `Yes` or `true` (ignoring case), `Some(false)` for any
+    /// other value and `None` if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesChannelExtension;
+    ///
+    /// let mut extension = ITunesChannelExtension::default();
+    /// assert_eq!(extension.complete_flag(), None);
+    /// extension.set_complete("yes".to_string());
+    /// assert_eq!(extension.complete_flag(), Some(true));
+    /// extension.set_complete("No".to_string());
+    /// assert_eq!(extension.complete_flag(), Some(false));
+    /// ```
+    pub fn complete_flag(&self) -> Option<bool> {
+        self.complete.as_deref().map(parse_flag)
+    }
+
     /// Return the new feed URL for this podcast.
     ///
     /// # Examples
@@ -7,12 +7,13 @@
 
 use std::collections::BTreeMap;
 use std::io::Write;
+use std::num::ParseIntError;
 
 use quick_xml::events::{BytesEnd, BytesStart, Event};
 use quick_xml::Error as XmlError;
 use quick_xml::Writer;
 
-use super::{parse_image, NAMESPACE};
+use super::{parse_flag, parse_image, NAMESPACE};
 use crate::extension::util::remove_extension_value;
 use crate::extension::Extension;
 use crate::toxml::{ToXml, WriterExt};
@@ -135,6 +136,27 @@ impl ITunesItemExtension {
         self.block = block.into();
     }
 
+    /// Return whether the episode should be hidden from the iTunes Store.
+    ///
+    /// Returns `Some(true)` if the value is `Yes` or `true` (ignoring case), `Some(false)` for any
+    /// other value and `None` if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesItemExtension;
+    ///
+    /// let mut extension = ITunesItemExtension::default();
+    /// assert_eq!(extension.blocked(), None);
+    /// extension.set_block("yes".to_string());
+    /// assert_eq!(extension.blocked(), Some(true));
+    /// extension.set_block("No".to_string());
+    /// assert_eq!(extension.blocked(), Some(false));
+    /// ```
+    pub fn blocked(&self) -> Option<bool> {
+        self.block.as_deref().map(parse_flag)
+    }
+
     /// Return the artwork URL for this podcast episode.
     ///
     /// # Examples
@@ -279,6 +301,27 @@ impl ITunesItemExtension {
         self.closed_captioned = closed_captioned.into();
     }
 
+    /// Return whether the episode has embedded closed captioning.
+    ///
+    /// Returns `Some(true)` if the value is `Yes` or `true` (ignoring case), `Some(false)` for any
+    /// other value and `None` if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesItemExtension;
+    ///
+    /// let mut extension = ITunesItemExtension::default();
+    /// assert_eq!(extension.closed_captioned_flag(), None);
+    /// extension.set_closed_captioned("yes".to_string());
+    /// assert_eq!(extension.closed_captioned_flag(), Some(true));
+    /// extension.set_closed_captioned("No".to_string());
+    /// assert_eq!(extension.closed_captioned_flag(), Some(false));
+    /// ```
+    pub fn closed_captioned_flag(&self) -> Option<bool> {
+        self.closed_captioned.as_deref().map(parse_flag)
+    }
+
     /// Return the value used to override the default sorting order for episodes.
     ///
     /// # Examples
@@ -311,6 +354,26 @@ impl ITunesItemExtension {
         self.order = order.into();
     }
 
+    /// Parse the sorting order of this podcast episode as a number.
+    ///
+    /// Returns `None` if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesItemExtension;
+    ///
+    /// let mut extension = ITunesItemExtension::default();
+    /// assert_eq!(extension.order_num(), None);
+    /// extension.set_order("3".to_string());
+    /// assert_eq!(extension.order_num(), Some(Ok(3)));
+    /// extension.set_order("three".to_string());
+    /// assert!(extension.order_num().unwrap().is_err());
+    /// ```
+    pub fn order_num(&self) -> Option<Result<u32, ParseIntError>> {
+        self.order.as_deref().map(|order| order.trim().parse())
+    }
+
     /// Return the description of this podcast episode.
     ///
     /// # Examples
@@ -448,6 +511,28 @@ impl ITunesItemExtension {
         self.episode = episode.into()
     }
 
+    /// Parse the episode number of this podcast episode as a number.
+    ///
+    /// Returns `None` if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesItemExtension;
+    ///
+    /// let mut extension = ITunesItemExtension::default();
+    /// assert_eq!(extension.episode_num(), None);
+    /// extension.set_episode("3".to_string());
+    /// assert_eq!(extension.episode_num(), Some(Ok(3)));
+    /// extension.set_episode("three".to_string());
+    /// assert!(extension.episode_num().unwrap().is_err());
+    /// ```
+    pub fn episode_num(&self) -> Option<Result<u32, ParseIntError>> {
+        self.episode
+            .as_deref()
+            .map(|episode| episode.trim().parse())
+    }
+
     /// Return the season of this podcast episode
     ///
     /// The season will be a string although it is typically a number in practice
@@ -485,6 +570,26 @@ impl ITunesItemExtension {
         self.season = season.into()
     }
 
+    /// Parse the season number of this podcast episode as a number.
+    ///
+    /// Returns `None` if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::itunes::ITunesItemExtension;
+    ///
+    /// let mut extension = ITunesItemExtension::default();
+    /// assert_eq!(extension.season_num(), None);
+    /// extension.set_season("3".to_string());
+    /// assert_eq!(extension.season_num(), Some(Ok(3)));
+    /// extension.set_season("three".to_string());
+    /// assert!(extension.season_num().unwrap().is_err());
+    /// ```
+    pub fn season_num(&self) -> Option<Result<u32, ParseIntError>> {
+        self.season.as_deref().map(|season| season.trim().parse())
+    }
+
     /// Return the episode_type of this podcast episode
     ///
     /// The episode type will be a string usually "full" "trailer" or "bonus"
@@ -30,11 +30,193 @@ pub(crate) fn is_itunes_namespace(ns: &str) -> bool {
     ns.eq_ignore_ascii_case(NAMESPACE)
 }
 
-fn parse_image(map: &mut BTreeMap<String, Vec<Extension>>) -> Option<String> {
-    let mut element = match map.remove("image").map(|mut v| v.remove(0)) {
-        Some(element) => element,
-        None => return None,
+/// Returns whether a flag such as `itunes:block` is set, i.e. is `Yes` or `true` ignoring case.
+fn parse_flag(value: &str) -> bool {
+    let value = value.trim();
+    value.eq_ignore_ascii_case("yes") || value.eq_ignore_ascii_case("true")
+}
+
+/// The podcast categories accepted by Apple Podcasts, with their subcategories.
+const CATEGORIES: &[(&str, &[&str])] = &[
+    (
+        "Arts",
+        &[
+            "Books",
+            "Design",
+            "Fashion & Beauty",
+            "Food",
+            "Performing Arts",
+            "Visual Arts",
+        ],
+    ),
+    (
+        "Business",
+        &[
+            "Careers",
+            "Entrepreneurship",
+            "Investing",
+            "Management",
+            "Marketing",
+            "Non-Profit",
+        ],
+    ),
+    ("Comedy", &["Comedy Interviews", "Improv", "Stand-Up"]),
+    (
+        "Education",
+        &["Courses", "How To", "Language Learning", "Self-Improvement"],
+    ),
+    ("Fiction", &["Comedy Fiction", "Drama", "Science Fiction"]),
+    ("Government", &[]),
+    ("History", &[]),
+    (
+        "Health & Fitness",
+        &[
+            "Alternative Health",
+            "Fitness",
+            "Medicine",
+            "Mental Health",
+            "Nutrition",
+            "Sexuality",
+        ],
+    ),
+    (
+        "Kids & Family",
+        &[
+            "Education for Kids",
+            "Parenting",
+            "Pets & Animals",
+            "Stories for Kids",
+        ],
+    ),
+    (
+        "Leisure",
+        &[
+            "Animation & Manga",
+            "Automotive",
+            "Aviation",
+            "Crafts",
+            "Games",
+            "Hobbies",
+            "Home & Garden",
+            "Video Games",
+        ],
+    ),
+    (
+        "Music",
+        &["Music Commentary", "Music History", "Music Interviews"],
+    ),
+    (
+        "News",
+        &[
+            "Business News",
+            "Daily News",
+            "Entertainment News",
+            "News Commentary",
+            "Politics",
+            "Sports News",
+            "Tech News",
+        ],
+    ),
+    (
+        "Religion & Spirituality",
+        &[
+            "Buddhism",
+            "Christianity",
+            "Hinduism",
+            "Islam",
+            "Judaism",
+            "Religion",
+            "Spirituality",
+        ],
+    ),
+    (
+        "Science",
+        &[
+            "Astronomy",
+            "Chemistry",
+            "Earth Sciences",
+            "Life Sciences",
+            "Mathematics",
+            "Natural Sciences",
+            "Nature",
+            "Physics",
+            "Social Sciences",
+        ],
+    ),
+    (
+        "Society & Culture",
+        &[
+            "Documentary",
+            "Personal Journals",
+            "Philosophy",
+            "Places & Travel",
+            "Relationships",
+        ],
+    ),
+    (
+        "Sports",
+        &[
+            "Baseball",
+            "Basketball",
+            "Cricket",
+            "Fantasy Sports",
+            "Football",
+            "Golf",
+            "Hockey",
+            "Rugby",
+            "Running",
+            "Soccer",
+            "Swimming",
+            "Tennis",
+            "Volleyball",
+            "Wilderness",
+            "Wrestling",
+        ],
+    ),
+    ("Technology", &[]),
+    ("True Crime", &[]),
+    (
+        "TV & Film",
+        &[
+            "After Shows",
+            "Film History",
+            "Film Interviews",
+            "Film Reviews",
+            "TV Reviews",
+        ],
+    ),
+];
+
+/// Returns the names of the category and its subcategories joined with ` > ` if the category
+/// is not in Apple's taxonomy.
+fn unknown_category(category: &ITunesCategory) -> Option<String> {
+    let subcategories = CATEGORIES
+        .iter()
+        .find(|(name, _)| *name == category.text())
+        .map(|(_, subcategories)| *subcategories);
+    let known = match (subcategories, category.subcategory()) {
+        (None, _) => false,
+        (Some(_), None) => true,
+        (Some(subcategories), Some(subcategory)) => {
+            subcategories.contains(&subcategory.text()) && subcategory.subcategory().is_none()
+        }
     };
+    if known {
+        return None;
+    }
+
+    let mut path = category.text().to_string();
+    let mut subcategory = category.subcategory();
+    while let Some(category) = subcategory {
+        path.push_str(" > ");
+        path.push_str(category.text());
+        subcategory = category.subcategory();
+    }
+    Some(path)
+}
+
+fn parse_image(map: &mut BTreeMap<String, Vec<Extension>>) -> Option<String> {
+    let mut element = map.remove("image").and_then(|v| v.into_iter().next())?;
 
     element.attrs.remove("href")
 }
@@ -51,7 +233,11 @@ fn parse_categories(map: &mut BTreeMap<String, Vec<Extension>>) -> Vec<ITunesCat
         let text = elem.attrs.remove("text").unwrap_or_default();
 
         let child = {
-            if let Some(mut child) = elem.children.remove("category").map(|mut v| v.remove(0)) {
+            if let Some(mut child) = elem
+                .children
+                .remove("category")
+                .and_then(|v| v.into_iter().next())
+            {
                 let text = child.attrs.remove("text").unwrap_or_default();
                 let mut category = ITunesCategory::default();
                 category.set_text(text);
@@ -71,16 +257,18 @@ fn parse_categories(map: &mut BTreeMap<String, Vec<Extension>>) -> Vec<ITunesCat
 }
 
 fn parse_owner(map: &mut BTreeMap<String, Vec<Extension>>) -> Option<ITunesOwner> {
-    if let Some(mut element) = map.remove("owner").map(|mut v| v.remove(0)) {
+    if let Some(mut element) = map.remove("owner").and_then(|v| v.into_iter().next()) {
         let name = element
             .children
             .remove("name")
-            .and_then(|mut v| v.remove(0).value);
+            .and_then(|v| v.into_iter().next())
+            .and_then(|ext| ext.value);
 
         let email = element
             .children
             .remove("email")
-            .and_then(|mut v| v.remove(0).value);
+            .and_then(|v| v.into_iter().next())
+            .and_then(|ext| ext.value);
 
         let mut owner = ITunesOwner::default();
         owner.set_name(name);
@@ -0,0 +1,557 @@
+// This file is part of rss.
+//
+// Copyright © 2015-2021 The rust-syndication Developers
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the MIT License and/or Apache 2.0 License.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use quick_xml::Error as XmlError;
+use quick_xml::Writer;
+
+use crate::extension::Extension;
+use crate::toxml::ToXml;
+
+/// The Media RSS XML namespace.
+pub const NAMESPACE: &str = "http://search.yahoo.com/mrss/";
+
+/// The prefix Media RSS elements are written with when the feed does not bind one.
+pub(crate) const PREFIX: &str = "media";
+
+/// Media RSS feeds commonly omit the trailing slash of the namespace, so both forms are accepted.
+#[inline]
+pub(crate) fn is_media_namespace(ns: &str) -> bool {
+    ns.strip_suffix('/').unwrap_or(ns) == NAMESPACE.trim_end_matches('/')
+}
+
+/// The type of a media object.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Medium {
+    /// An image.
+    Image,
+    /// An audio recording.
+    Audio,
+    /// A video.
+    Video,
+    /// A document.
+    Document,
+    /// An executable.
+    Executable,
+}
+
+impl FromStr for Medium {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "image" => Ok(Medium::Image),
+            "audio" => Ok(Medium::Audio),
+            "video" => Ok(Medium::Video),
+            "document" => Ok(Medium::Document),
+            "executable" => Ok(Medium::Executable),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Medium {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Medium::Image => write!(f, "image"),
+            Medium::Audio => write!(f, "audio"),
+            Medium::Video => write!(f, "video"),
+            Medium::Document => write!(f, "document"),
+            Medium::Executable => write!(f, "executable"),
+        }
+    }
+}
+
+/// A `media:content` element.
+///
+/// Attributes that are not modeled, or whose value cannot be parsed into the typed field, are
+/// kept in `extras` so they are written back unchanged.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "builders", derive(Builder))]
+#[cfg_attr(
+    feature = "builders",
+    builder(
+        setter(into),
+        default,
+        build_fn(name = "build_impl", private, error = "never::Never")
+    )
+)]
+pub struct MediaContent {
+    /// The URL of the media object.
+    pub url: Option<String>,
+    /// The MIME type of the media object.
+    pub mime_type: Option<String>,
+    /// The type of the media object.
+    pub medium: Option<Medium>,
+    /// The size of the media object in bytes.
+    pub file_size: Option<u64>,
+    /// The play time of the media object.
+    pub duration: Option<Duration>,
+    /// The width of the media object in pixels.
+    pub width: Option<u32>,
+    /// The height of the media object in pixels.
+    pub height: Option<u32>,
+    /// The bitrate of the media object in kilobits per second.
+    pub bitrate: Option<u32>,
+    /// Other attributes of the element.
+    #[cfg_attr(feature = "builders", builder(setter(each = "extra")))]
+    pub extras: BTreeMap<String, String>,
+    /// The child elements, such as `media:title` or `media:thumbnail`. This is a map of local
+    /// names to child elements.
+    #[cfg_attr(feature = "builders", builder(setter(each = "child")))]
+    pub children: BTreeMap<String, Vec<Extension>>,
+}
+
+impl MediaContent {
+    /// Return the URL of this media object.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Set the URL of this media object.
+    pub fn set_url<V>(&mut self, url: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.url = url.into();
+    }
+
+    /// Return the MIME type of this media object.
+    pub fn mime_type(&self) -> Option<&str> {
+        self.mime_type.as_deref()
+    }
+
+    /// Set the MIME type of this media object.
+    pub fn set_mime_type<V>(&mut self, mime_type: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.mime_type = mime_type.into();
+    }
+
+    /// Return the type of this media object.
+    pub fn medium(&self) -> Option<Medium> {
+        self.medium
+    }
+
+    /// Set the type of this media object.
+    pub fn set_medium<V>(&mut self, medium: V)
+    where
+        V: Into<Option<Medium>>,
+    {
+        self.medium = medium.into();
+    }
+
+    /// Return the size of this media object in bytes.
+    pub fn file_size(&self) -> Option<u64> {
+        self.file_size
+    }
+
+    /// Set the size of this media object in bytes.
+    pub fn set_file_size<V>(&mut self, file_size: V)
+    where
+        V: Into<Option<u64>>,
+    {
+        self.file_size = file_size.into();
+    }
+
+    /// Return the play time of this media object.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Set the play time of this media object.
+    pub fn set_duration<V>(&mut self, duration: V)
+    where
+        V: Into<Option<Duration>>,
+    {
+        self.duration = duration.into();
+    }
+
+    /// Return the width of this media object in pixels.
+    pub fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    /// Set the width of this media object in pixels.
+    pub fn set_width<V>(&mut self, width: V)
+    where
+        V: Into<Option<u32>>,
+    {
+        self.width = width.into();
+    }
+
+    /// Return the height of this media object in pixels.
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    /// Set the height of this media object in pixels.
+    pub fn set_height<V>(&mut self, height: V)
+    where
+        V: Into<Option<u32>>,
+    {
+        self.height = height.into();
+    }
+
+    /// Return the bitrate of this media object in kilobits per second.
+    pub fn bitrate(&self) -> Option<u32> {
+        self.bitrate
+    }
+
+    /// Set the bitrate of this media object in kilobits per second.
+    pub fn set_bitrate<V>(&mut self, bitrate: V)
+    where
+        V: Into<Option<u32>>,
+    {
+        self.bitrate = bitrate.into();
+    }
+
+    /// Return the attributes of this element that are not modeled by the other fields.
+    pub fn extras(&self) -> &BTreeMap<String, String> {
+        &self.extras
+    }
+
+    /// Return the child elements of this element.
+    pub fn children(&self) -> &BTreeMap<String, Vec<Extension>> {
+        &self.children
+    }
+
+    /// Return the `media:thumbnail` child elements of this element.
+    pub fn thumbnails(&self) -> Vec<MediaThumbnail> {
+        self.children
+            .get("thumbnail")
+            .into_iter()
+            .flatten()
+            .cloned()
+            .map(MediaThumbnail::from_extension)
+            .collect()
+    }
+}
+
+impl MediaContent {
+    /// Creates a `MediaContent` from a parsed `media:content` element.
+    pub fn from_extension(extension: Extension) -> Self {
+        let mut content = MediaContent {
+            children: extension.children,
+            ..Default::default()
+        };
+
+        for (key, value) in extension.attrs {
+            match key.as_str() {
+                "url" => content.url = Some(value),
+                "type" => content.mime_type = Some(value),
+                "medium" => match value.parse() {
+                    Ok(medium) => content.medium = Some(medium),
+                    Err(_) => {
+                        content.extras.insert(key, value);
+                    }
+                },
+                "fileSize" => match value.trim().parse() {
+                    Ok(file_size) => content.file_size = Some(file_size),
+                    Err(_) => {
+                        content.extras.insert(key, value);
+                    }
+                },
+                "duration" => match parse_duration(&value) {
+                    Some(duration) => content.duration = Some(duration),
+                    None => {
+                        content.extras.insert(key, value);
+                    }
+                },
+                "width" => match value.trim().parse() {
+                    Ok(width) => content.width = Some(width),
+                    Err(_) => {
+                        content.extras.insert(key, value);
+                    }
+                },
+                "height" => match value.trim().parse() {
+                    Ok(height) => content.height = Some(height),
+                    Err(_) => {
+                        content.extras.insert(key, value);
+                    }
+                },
+                "bitrate" => match value.trim().parse() {
+                    Ok(bitrate) => content.bitrate = Some(bitrate),
+                    Err(_) => {
+                        content.extras.insert(key, value);
+                    }
+                },
+                _ => {
+                    content.extras.insert(key, value);
+                }
+            }
+        }
+
+        content
+    }
+
+    /// Returns the `media:content` element for this media object, using the given prefix.
+    pub(crate) fn to_extension(&self, prefix: &str) -> Extension {
+        let mut attrs = self.extras.clone();
+        let mut attr = |key: &str, value: Option<String>| {
+            if let Some(value) = value {
+                attrs.insert(key.to_string(), value);
+            }
+        };
+        attr("url", self.url.clone());
+        attr("type", self.mime_type.clone());
+        attr("medium", self.medium.map(|medium| medium.to_string()));
+        attr("fileSize", self.file_size.map(|size| size.to_string()));
+        attr("duration", self.duration.map(format_duration));
+        attr("width", self.width.map(|width| width.to_string()));
+        attr("height", self.height.map(|height| height.to_string()));
+        attr("bitrate", self.bitrate.map(|bitrate| bitrate.to_string()));
+
+        Extension {
+            name: format!("{}:content", prefix),
+            value: None,
+            attrs,
+            children: self.children.clone(),
+        }
+    }
+}
+
+/// Parse a duration given in seconds, which may be fractional.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration.subsec_nanos() == 0 {
+        duration.as_secs().to_string()
+    } else {
+        duration.as_secs_f64().to_string()
+    }
+}
+
+#[cfg(feature = "builders")]
+impl MediaContentBuilder {
+    /// Builds a new `MediaContent`.
+    pub fn build(&self) -> MediaContent {
+        self.build_impl().unwrap()
+    }
+}
+
+/// A `media:thumbnail` element.
+///
+/// Attributes that are not modeled, or whose value cannot be parsed into the typed field, are
+/// kept in `extras` so they are written back unchanged.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "builders", derive(Builder))]
+#[cfg_attr(
+    feature = "builders",
+    builder(
+        setter(into),
+        default,
+        build_fn(name = "build_impl", private, error = "never::Never")
+    )
+)]
+pub struct MediaThumbnail {
+    /// The URL of the thumbnail.
+    pub url: String,
+    /// The width of the thumbnail in pixels.
+    pub width: Option<u32>,
+    /// The height of the thumbnail in pixels.
+    pub height: Option<u32>,
+    /// The time offset of the thumbnail in the media object, in NTP format.
+    pub time: Option<String>,
+    /// Other attributes of the element.
+    #[cfg_attr(feature = "builders", builder(setter(each = "extra")))]
+    pub extras: BTreeMap<String, String>,
+}
+
+impl MediaThumbnail {
+    /// Return the URL of this thumbnail.
+    pub fn url(&self) -> &str {
+        self.url.as_str()
+    }
+
+    /// Set the URL of this thumbnail.
+    pub fn set_url<V>(&mut self, url: V)
+    where
+        V: Into<String>,
+    {
+        self.url = url.into();
+    }
+
+    /// Return the width of this thumbnail in pixels.
+    pub fn width(&self) -> Option<u32> {
+        self.width
+    }
+
+    /// Set the width of this thumbnail in pixels.
+    pub fn set_width<V>(&mut self, width: V)
+    where
+        V: Into<Option<u32>>,
+    {
+        self.width = width.into();
+    }
+
+    /// Return the height of this thumbnail in pixels.
+    pub fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    /// Set the height of this thumbnail in pixels.
+    pub fn set_height<V>(&mut self, height: V)
+    where
+        V: Into<Option<u32>>,
+    {
+        self.height = height.into();
+    }
+
+    /// Return the time offset of this thumbnail in the media object.
+    pub fn time(&self) -> Option<&str> {
+        self.time.as_deref()
+    }
+
+    /// Set the time offset of this thumbnail in the media object.
+    pub fn set_time<V>(&mut self, time: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.time = time.into();
+    }
+
+    /// Return the attributes of this element that are not modeled by the other fields.
+    pub fn extras(&self) -> &BTreeMap<String, String> {
+        &self.extras
+    }
+}
+
+impl MediaThumbnail {
+    /// Creates a `MediaThumbnail` from a parsed `media:thumbnail` element.
+    pub fn from_extension(extension: Extension) -> Self {
+        let mut thumbnail = MediaThumbnail::default();
+
+        for (key, value) in extension.attrs {
+            match key.as_str() {
+                "url" => thumbnail.url = value,
+                "time" => thumbnail.time = Some(value),
+                "width" => match value.trim().parse() {
+                    Ok(width) => thumbnail.width = Some(width),
+                    Err(_) => {
+                        thumbnail.extras.insert(key, value);
+                    }
+                },
+                "height" => match value.trim().parse() {
+                    Ok(height) => thumbnail.height = Some(height),
+                    Err(_) => {
+                        thumbnail.extras.insert(key, value);
+                    }
+                },
+                _ => {
+                    thumbnail.extras.insert(key, value);
+                }
+            }
+        }
+
+        thumbnail
+    }
+}
+
+#[cfg(feature = "builders")]
+impl MediaThumbnailBuilder {
+    /// Builds a new `MediaThumbnail`.
+    pub fn build(&self) -> MediaThumbnail {
+        self.build_impl().unwrap()
+    }
+}
+
+/// A Media RSS element extension.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "builders", derive(Builder))]
+#[cfg_attr(
+    feature = "builders",
+    builder(
+        setter(into),
+        default,
+        build_fn(name = "build_impl", private, error = "never::Never")
+    )
+)]
+pub struct MediaExtension {
+    /// The `media:content` elements.
+    #[cfg_attr(feature = "builders", builder(setter(each = "content")))]
+    pub contents: Vec<MediaContent>,
+}
+
+impl MediaExtension {
+    /// Return the `media:content` elements.
+    pub fn contents(&self) -> &[MediaContent] {
+        &self.contents
+    }
+
+    /// Return a mutable slice of the `media:content` elements.
+    pub fn contents_mut(&mut self) -> &mut [MediaContent] {
+        &mut self.contents
+    }
+
+    /// Set the `media:content` elements.
+    pub fn set_contents<V>(&mut self, contents: V)
+    where
+        V: Into<Vec<MediaContent>>,
+    {
+        self.contents = contents.into();
+    }
+}
+
+impl MediaExtension {
+    /// Serializes this extension to the nominated writer, using the given prefix.
+    pub(crate) fn to_xml_with_prefix<W: Write>(
+        &self,
+        prefix: &str,
+        writer: &mut Writer<W>,
+    ) -> Result<(), XmlError> {
+        for content in &self.contents {
+            content.to_extension(prefix).to_xml(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether elements with this local name are modeled by `MediaExtension`.
+    pub(crate) fn handles(name: &str) -> bool {
+        name == "content"
+    }
+
+    /// Creates a `MediaExtension` using the specified `BTreeMap`.
+    pub fn from_map(mut map: BTreeMap<String, Vec<Extension>>) -> Self {
+        let contents = map
+            .remove("content")
+            .unwrap_or_default()
+            .into_iter()
+            .map(MediaContent::from_extension)
+            .collect();
+
+        Self { contents }
+    }
+}
+
+#[cfg(feature = "builders")]
+impl MediaExtensionBuilder {
+    /// Builds a new `MediaExtension`.
+    pub fn build(&self) -> MediaExtension {
+        self.build_impl().unwrap()
+    }
+}
@@ -11,8 +11,10 @@ use std::str;
 
 use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::Error as XmlError;
+use quick_xml::Reader;
 use quick_xml::Writer;
 
+use crate::error::Error;
 use crate::toxml::ToXml;
 
 /// Types and methods for [Atom](https://www.rssboard.org/rss-profile#namespace-elements-atom) extensions.
@@ -29,12 +31,19 @@ pub mod dublincore;
 /// Types and methods for [Syndication](http://web.resource.org/rss/1.0/modules/syndication/) extensions.
 pub mod syndication;
 
+/// Types and methods for [Media RSS](https://www.rssboard.org/media-rss) extensions.
+pub mod media;
+
 pub(crate) mod util;
 
 /// A map of extension namespace prefixes to local names to elements.
 pub type ExtensionMap = BTreeMap<String, BTreeMap<String, Vec<Extension>>>;
 
 /// A namespaced extension such as iTunes or Dublin Core.
+///
+/// Attributes and children are stored in sorted maps, so the original document order is not
+/// preserved. When written, attributes are emitted sorted by name and children are grouped by
+/// local name in sorted order. Children sharing a local name keep their relative order.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Default, Clone, PartialEq)]
 #[cfg_attr(feature = "builders", derive(Builder))]
@@ -51,11 +60,11 @@ pub struct Extension {
     pub name: String,
     /// The content of the extension element.
     pub value: Option<String>,
-    /// The attributes for the extension element.
+    /// The attributes for the extension element, sorted by name.
     #[cfg_attr(feature = "builders", builder(setter(each = "attr")))]
     pub attrs: BTreeMap<String, String>,
     /// The children of the extension element. This is a map of local names to child
-    /// elements.
+    /// elements, sorted by local name.
     #[cfg_attr(feature = "builders", builder(setter(each = "child")))]
     pub children: BTreeMap<String, Vec<Extension>>,
 }
@@ -88,13 +97,16 @@ impl Extension {
     }
 
     /// Return the attributes for the extension element.
+    ///
+    /// Attributes are sorted by name rather than kept in document order.
     pub fn attrs(&self) -> &BTreeMap<String, String> {
         &self.attrs
     }
 
     /// Return the children of the extension element.
     ///
-    /// This is a map of local names to child elements.
+    /// This is a map of local names to child elements. Children are sorted by local name rather
+    /// than kept in document order; children sharing a local name keep their relative order.
     pub fn children(&self) -> &BTreeMap<String, Vec<Extension>> {
         &self.children
     }
@@ -126,3 +138,42 @@ impl ExtensionBuilder {
         self.build_impl().unwrap()
     }
 }
+
+/// Parse the elements of an XML fragment into extensions.
+///
+/// Each top-level element of the fragment becomes one `Extension`. Text and other content
+/// between the top-level elements is ignored. Namespace prefixes are kept in the names as
+/// written, and namespace declarations are kept as attributes.
+///
+/// # Examples
+///
+/// ```
+/// use rss::extension::parse_fragment;
+///
+/// let extensions = parse_fragment(r#"<x:tag a="1"><x:child>v</x:child></x:tag>"#).unwrap();
+/// assert_eq!(extensions.len(), 1);
+/// assert_eq!(extensions[0].name(), "x:tag");
+/// assert_eq!(extensions[0].children()["child"][0].value(), Some("v"));
+/// ```
+pub fn parse_fragment(xml: &str) -> Result<Vec<Extension>, Error> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().expand_empty_elements = true;
+    let mut extensions = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(element) => {
+                extensions.push(util::parse_extension_element(
+                    &mut reader,
+                    element.attributes(),
+                )?);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(extensions)
+}
@@ -21,6 +21,7 @@ pub const NAMESPACE: &str = "http://purl.org/rss/1.0/modules/syndication/";
 
 /// The unit of time between updates/refreshes
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 #[derive(Debug, Clone, PartialEq)]
 pub enum UpdatePeriod {
     /// refresh hourly
@@ -124,11 +125,11 @@ impl SyndicationExtension {
             if NAMESPACE == namespace {
                 writer.write_text_element(
                     format!("{}:updatePeriod", prefix),
-                    &self.period.to_string(),
+                    self.period.to_string(),
                 )?;
                 writer.write_text_element(
                     format!("{}:updateFrequency", prefix),
-                    &format!("{}", self.frequency),
+                    format!("{}", self.frequency),
                 )?;
                 writer.write_text_element(format!("{}:updateBase", prefix), &self.base)?;
             }
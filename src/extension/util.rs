@@ -18,6 +18,96 @@ use crate::error::Error;
 use crate::extension::{Extension, ExtensionMap};
 use crate::util::{attr_value, decode};
 
+/// The Atom namespace, also used without the `atom` feature.
+pub(crate) const ATOM_NAMESPACE: &str = "http://www.w3.org/2005/Atom";
+
+/// The [Podcasting 2.0](https://podcastindex.org/namespace/1.0) namespace.
+pub(crate) const PODCAST_NAMESPACE: &str = "https://podcastindex.org/namespace/1.0";
+
+/// Returns the declaration of the `atom` prefix if it is used by the extensions. Without the
+/// `atom` feature Atom elements are kept as generic extensions under their prefix.
+#[cfg(not(feature = "atom"))]
+pub(crate) fn atom_namespace_declaration(extensions: &ExtensionMap) -> Option<(String, String)> {
+    extensions
+        .contains_key("atom")
+        .then(|| ("atom".to_string(), ATOM_NAMESPACE.to_string()))
+}
+
+/// Renames an element with the `from` prefix, and its children and attributes with that prefix,
+/// to the `to` prefix.
+fn rename_prefix(mut extension: Extension, from: &str, to: &str) -> Extension {
+    let rename = |name: &str| {
+        name.strip_prefix(from)
+            .and_then(|name| name.strip_prefix(':'))
+            .map(|local| format!("{}:{}", to, local))
+    };
+    if let Some(name) = rename(&extension.name) {
+        extension.name = name;
+    }
+    extension.attrs = std::mem::take(&mut extension.attrs)
+        .into_iter()
+        .map(|(key, value)| (rename(&key).unwrap_or(key), value))
+        .collect();
+    for children in extension.children.values_mut() {
+        for child in std::mem::take(children) {
+            children.push(rename_prefix(child, from, to));
+        }
+    }
+    extension
+}
+
+/// Moves the extensions under the `from` prefix to the `to` prefix.
+pub(crate) fn rename_extensions(extensions: &mut ExtensionMap, from: &str, to: &str) {
+    if let Some(map) = extensions.remove(from) {
+        let target = extensions.entry(to.to_string()).or_default();
+        for (name, elements) in map {
+            target
+                .entry(name)
+                .or_default()
+                .extend(elements.into_iter().map(|ext| rename_prefix(ext, from, to)));
+        }
+    }
+}
+
+/// Binds each namespace to a single prefix, the first in alphabetical order, and moves the
+/// extensions of the other prefixes to it. Returns the replaced prefixes and their replacements.
+pub(crate) fn dedup_namespaces(
+    namespaces: &mut BTreeMap<String, String>,
+    extensions: &mut ExtensionMap,
+) -> BTreeMap<String, String> {
+    let mut kept = BTreeMap::new();
+    let mut renames = BTreeMap::new();
+    for (prefix, uri) in namespaces.iter() {
+        match kept.get(uri) {
+            Some(first) => renames.insert(prefix.clone(), String::clone(first)),
+            None => kept.insert(uri, prefix.clone()),
+        };
+    }
+    for (from, to) in &renames {
+        namespaces.remove(from);
+        rename_extensions(extensions, from, to);
+    }
+    renames
+}
+
+/// Records the namespaces bound in `scope` to the prefixes of an element and its children.
+pub(crate) fn record_namespaces(
+    namespaces: &mut BTreeMap<String, String>,
+    extension: &Extension,
+    scope: &BTreeMap<String, String>,
+) {
+    if let Some((prefix, _)) = extension_name(&extension.name) {
+        if let Some(uri) = scope.get(prefix) {
+            namespaces
+                .entry(prefix.to_string())
+                .or_insert_with(|| uri.clone());
+        }
+    }
+    for child in extension.children.values().flatten() {
+        record_namespaces(namespaces, child, scope);
+    }
+}
+
 pub(crate) fn read_namespace_declarations<'m, R>(
     reader: &mut Reader<R>,
     mut atts: Attributes,
@@ -45,6 +135,29 @@ pub(crate) fn extension_name(element_name: &str) -> Option<(&str, &str)> {
     Some((ns, name))
 }
 
+/// Namespaces that RSS 0.90, RSS 1.0 and some RSS 2.0 feeds use for the core elements.
+const RSS_NAMESPACES: &[&str] = &[
+    "http://my.netscape.com/rdf/simple/0.9/",
+    "http://purl.org/rss/1.0/",
+    "http://backend.userland.com/rss2",
+    "http://blogs.law.harvard.edu/tech/rss",
+];
+
+/// Strip the prefix from an element name if it is bound to one of the RSS core namespaces.
+pub(crate) fn core_element_name<'n>(
+    element_name: &'n str,
+    namespaces: &BTreeMap<String, String>,
+) -> &'n str {
+    if let Some((prefix, name)) = extension_name(element_name) {
+        if let Some(ns) = namespaces.get(prefix) {
+            if RSS_NAMESPACES.contains(&ns.as_str()) {
+                return name;
+            }
+        }
+    }
+    element_name
+}
+
 pub(crate) fn extension_entry<'e>(
     extensions: &'e mut ExtensionMap,
     ns: &str,
@@ -106,6 +219,23 @@ pub(crate) fn parse_extension_element<R: BufRead>(
     Ok(extension)
 }
 
+/// Sorts the elements of every extension by name, value and attributes, including their
+/// children.
+pub(crate) fn sort_extensions(extensions: &mut ExtensionMap) {
+    for elements in extensions.values_mut().flat_map(BTreeMap::values_mut) {
+        sort_extension_elements(elements);
+    }
+}
+
+fn sort_extension_elements(elements: &mut [Extension]) {
+    for element in elements.iter_mut() {
+        for children in element.children.values_mut() {
+            sort_extension_elements(children);
+        }
+    }
+    elements.sort_by(|a, b| (&a.name, &a.value, &a.attrs).cmp(&(&b.name, &b.value, &b.attrs)));
+}
+
 pub fn get_extension_values(v: Vec<Extension>) -> Vec<String> {
     v.into_iter()
         .filter_map(|ext| ext.value)
@@ -117,6 +247,6 @@ pub fn remove_extension_value(
     key: &str,
 ) -> Option<String> {
     map.remove(key)
-        .map(|mut v| v.remove(0))
+        .and_then(|v| v.into_iter().next())
         .and_then(|ext| ext.value)
 }
@@ -5,6 +5,7 @@
 // This program is free software; you can redistribute it and/or modify
 // it under the terms of the MIT License and/or Apache 2.0 License.
 
+use std::collections::BTreeMap;
 use std::io::{BufRead, Write};
 
 use quick_xml::events::attributes::Attributes;
@@ -15,7 +16,7 @@ use quick_xml::Writer;
 
 use crate::error::Error;
 use crate::toxml::ToXml;
-use crate::util::{decode, element_text};
+use crate::util::{attr_value, decode, element_text};
 
 /// Represents the GUID of an RSS item.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -33,7 +34,14 @@ pub struct Guid {
     /// The value of the GUID.
     pub value: String,
     /// Indicates if the GUID is a permalink.
+    ///
+    /// The flag is always written as an explicit `isPermaLink` attribute, so a GUID whose value is
+    /// not a URL must have it set to `false` to be read back as a plain identifier.
     pub permalink: bool,
+    /// Other attributes of the element, such as a non-standard `domain`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "builders", builder(setter(each = "extra")))]
+    pub extras: BTreeMap<String, String>,
 }
 
 impl Guid {
@@ -100,6 +108,26 @@ impl Guid {
     {
         self.value = value.into();
     }
+
+    /// Return the attributes of this GUID other than `isPermaLink`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Guid;
+    ///
+    /// let mut guid = Guid::default();
+    /// guid.extras_mut().insert("domain".to_string(), "example.com".to_string());
+    /// assert_eq!(guid.extras()["domain"], "example.com");
+    /// ```
+    pub fn extras(&self) -> &BTreeMap<String, String> {
+        &self.extras
+    }
+
+    /// Return a mutable reference to the attributes of this GUID other than `isPermaLink`.
+    pub fn extras_mut(&mut self) -> &mut BTreeMap<String, String> {
+        &mut self.extras
+    }
 }
 
 impl Default for Guid {
@@ -108,6 +136,7 @@ impl Default for Guid {
         Guid {
             value: Default::default(),
             permalink: true,
+            extras: BTreeMap::new(),
         }
     }
 }
@@ -121,9 +150,14 @@ impl Guid {
         let mut guid = Guid::default();
 
         for attr in atts.with_checks(false).flatten() {
-            if decode(attr.key.as_ref(), reader)?.as_ref() == "isPermaLink" {
-                guid.permalink = &*attr.value != b"false";
-                break;
+            let key = decode(attr.key.as_ref(), reader)?;
+            let value = attr_value(&attr, reader)?;
+            if key == "isPermaLink" {
+                // anything but a false value, including an unparseable one, keeps the default
+                let value = value.trim();
+                guid.permalink = !(value.eq_ignore_ascii_case("false") || value == "0");
+            } else {
+                guid.extras.insert(key.to_string(), value.to_string());
             }
         }
 
@@ -136,9 +170,10 @@ impl ToXml for Guid {
     fn to_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), XmlError> {
         let name = "guid";
         let mut element = BytesStart::new(name);
-        if !self.permalink {
-            element.push_attribute(("isPermaLink", "false"));
-        }
+        // the attribute is always written, a reader that ignores the default of `true` does not
+        // take an identifier for a URL
+        element.push_attribute(("isPermaLink", if self.permalink { "true" } else { "false" }));
+        element.extend_attributes(self.extras.iter().map(|(k, v)| (k.as_str(), v.as_str())));
         writer.write_event(Event::Start(element))?;
         writer.write_event(Event::Text(BytesText::new(&self.value)))?;
         writer.write_event(Event::End(BytesEnd::new(name)))?;
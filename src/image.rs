@@ -302,6 +302,34 @@ impl ToXml for Image {
 
 #[cfg(feature = "builders")]
 impl ImageBuilder {
+    /// Set the width of the image in pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::ImageBuilder;
+    ///
+    /// let image = ImageBuilder::default().width_px(80).build();
+    /// assert_eq!(image.width(), Some("80"));
+    /// ```
+    pub fn width_px(&mut self, width: u32) -> &mut Self {
+        self.width(width.to_string())
+    }
+
+    /// Set the height of the image in pixels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::ImageBuilder;
+    ///
+    /// let image = ImageBuilder::default().height_px(31).build();
+    /// assert_eq!(image.height(), Some("31"));
+    /// ```
+    pub fn height_px(&mut self, height: u32) -> &mut Self {
+        self.height(height.to_string())
+    }
+
     /// Builds a new `Image`.
     pub fn build(&self) -> Image {
         self.build_impl().unwrap()
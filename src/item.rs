@@ -5,7 +5,8 @@
 // This program is free software; you can redistribute it and/or modify
 // it under the terms of the MIT License and/or Apache 2.0 License.
 
-use std::collections::BTreeMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::{BufRead, Write};
 
 use quick_xml::events::attributes::Attributes;
@@ -15,24 +16,33 @@ use quick_xml::Reader;
 use quick_xml::Writer;
 
 use crate::category::Category;
-use crate::enclosure::Enclosure;
+use crate::enclosure::{Enclosure, EnclosureMetadata, HeadClient};
 use crate::error::Error;
 #[cfg(feature = "atom")]
 use crate::extension::atom;
 use crate::extension::dublincore;
 use crate::extension::itunes::{self, is_itunes_namespace};
+use crate::extension::media::{self, is_media_namespace};
+#[cfg(not(feature = "atom"))]
+use crate::extension::util::atom_namespace_declaration;
 use crate::extension::util::{
-    extension_entry, extension_name, parse_extension_element, read_namespace_declarations,
+    core_element_name, dedup_namespaces, extension_entry, extension_name, parse_extension_element,
+    read_namespace_declarations, record_namespaces, rename_extensions, sort_extensions,
+    PODCAST_NAMESPACE,
 };
 use crate::extension::ExtensionMap;
 use crate::guid::Guid;
+use crate::options::{ReadOptions, WriteOptions};
 use crate::source::Source;
 use crate::toxml::{ToXml, WriterExt};
-use crate::util::{decode, element_text, skip};
+use crate::util::{
+    decode, element_text_with, fnv1a_64, html_to_text, normalize_categories, normalize_date, skip,
+    trim_in_place, truncate_words, xml_lang,
+};
 
 /// Represents an item in an RSS feed.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(feature = "builders", derive(Builder))]
 #[cfg_attr(
     feature = "builders",
@@ -64,8 +74,11 @@ pub struct Item {
     pub pub_date: Option<String>,
     /// The RSS channel the item came from.
     pub source: Option<Source>,
-    /// The HTML contents of the item.
+    /// The HTML contents of the item. When an item contains several `content:encoded`
+    /// elements, the first non-empty one is kept.
     pub content: Option<String>,
+    /// The `xml:lang` attribute of the item element.
+    pub xml_lang: Option<String>,
     /// The extensions for the item.
     #[cfg_attr(feature = "builders", builder(setter(each = "extension")))]
     pub extensions: ExtensionMap,
@@ -76,6 +89,17 @@ pub struct Item {
     pub itunes_ext: Option<itunes::ITunesItemExtension>,
     /// The Dublin Core extension for the item.
     pub dublin_core_ext: Option<dublincore::DublinCoreExtension>,
+    /// The Media RSS extension for the item.
+    pub media_ext: Option<media::MediaExtension>,
+    /// The namespaces of the prefixes used by the extensions of the item, as they were bound where
+    /// the item was read. A prefix that the channel binds to another namespace is declared on the
+    /// item element when writing.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "builders", builder(setter(each = "namespace")))]
+    pub namespaces: BTreeMap<String, String>,
+    /// The source of the item as it was read, if `ReadOptions::capture_raw_items` was set. It is
+    /// not used when writing the item.
+    pub raw_xml: Option<String>,
 }
 
 impl Item {
@@ -143,6 +167,32 @@ impl Item {
         self.link = link.into();
     }
 
+    /// Resolve the link of this item against a base URL, such as the URL the feed was fetched
+    /// from. Absolute links are returned unchanged.
+    ///
+    /// Returns `None` if the item has no link or the link cannot be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    /// use url::Url;
+    ///
+    /// let base = Url::parse("http://example.com/blog/feed.xml").unwrap();
+    /// let mut item = Item::default();
+    /// assert_eq!(item.resolved_link(&base), None);
+    ///
+    /// item.set_link("posts/1".to_string());
+    /// assert_eq!(
+    ///     item.resolved_link(&base).unwrap().as_str(),
+    ///     "http://example.com/blog/posts/1"
+    /// );
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn resolved_link(&self, base: &url::Url) -> Option<url::Url> {
+        self.link().and_then(|link| base.join(link).ok())
+    }
+
     /// Return the description of this item.
     ///
     /// # Examples
@@ -244,6 +294,99 @@ impl Item {
         self.categories = categories.into();
     }
 
+    /// Return the categories of this item without duplicates.
+    ///
+    /// Categories with the same domain and the same name, ignoring case and surrounding
+    /// whitespace, are duplicates. The first occurrence is kept unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Category, Item};
+    ///
+    /// let mut item = Item::default();
+    /// item.set_categories(vec![Category::from("Rust"), Category::from(" rust"), Category::from("XML")]);
+    ///
+    /// let names = item
+    ///     .normalized_categories()
+    ///     .iter()
+    ///     .map(|category| category.name().to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, ["Rust", "XML"]);
+    /// ```
+    pub fn normalized_categories(&self) -> Vec<Category> {
+        let mut seen = BTreeSet::new();
+        self.categories
+            .iter()
+            .filter(|category| {
+                seen.insert((category.domain(), category.name().trim().to_lowercase()))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Bring this item into a canonical form so that equivalent items compare equal.
+    ///
+    /// The title, link, description, author, comments, GUID, publication date and content are
+    /// trimmed, as are the fields of the enclosure and source. The publication date is written
+    /// as RFC 2822 if it can be parsed, which requires the `validation` feature. Categories are
+    /// trimmed and sorted by domain and name. Prefixes of the item's namespaces that are bound
+    /// to the same namespace are merged into the first of them in alphabetical order, and the
+    /// elements of each extension are sorted. Nothing but surrounding whitespace is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Category, Item};
+    ///
+    /// let mut item = Item::default();
+    /// item.set_title(" Title\n".to_string());
+    /// item.set_categories(vec![Category::from("XML"), Category::from(" Rust")]);
+    /// item.normalize();
+    ///
+    /// assert_eq!(item.title(), Some("Title"));
+    /// assert_eq!(item.categories()[0].name(), "Rust");
+    /// ```
+    pub fn normalize(&mut self) {
+        for text in [
+            &mut self.title,
+            &mut self.link,
+            &mut self.description,
+            &mut self.author,
+            &mut self.comments,
+            &mut self.content,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            trim_in_place(text);
+        }
+        if let Some(guid) = self.guid.as_mut() {
+            trim_in_place(&mut guid.value);
+        }
+        if let Some(enclosure) = self.enclosure.as_mut() {
+            for text in [
+                &mut enclosure.url,
+                &mut enclosure.length,
+                &mut enclosure.mime_type,
+            ] {
+                trim_in_place(text);
+            }
+        }
+        if let Some(source) = self.source.as_mut() {
+            trim_in_place(&mut source.url);
+            if let Some(title) = source.title.as_mut() {
+                trim_in_place(title);
+            }
+        }
+        if let Some(date) = self.pub_date.as_mut() {
+            normalize_date(date);
+        }
+        normalize_categories(&mut self.categories);
+        dedup_namespaces(&mut self.namespaces, &mut self.extensions);
+        sort_extensions(&mut self.extensions);
+    }
+
     /// Return the URL for comments about this item.
     ///
     /// # Examples
@@ -308,6 +451,51 @@ impl Item {
         self.enclosure = enclosure.into();
     }
 
+    /// Set the enclosure of this item to `url`, requesting its length and MIME type with `client`.
+    ///
+    /// This sends a `HEAD` request for `url` through the client. The length and MIME type are
+    /// taken from the `Content-Length` and `Content-Type` headers. If a header is missing or
+    /// the request fails, the length is `0` and the MIME type is inferred from the URL's file
+    /// extension as by `Enclosure::inferred_mime_type`. The enclosure is set in either case and
+    /// the error of a failed request is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{EnclosureMetadata, HeadClient, Item};
+    ///
+    /// struct Offline;
+    ///
+    /// impl HeadClient for Offline {
+    ///     type Error = std::io::Error;
+    ///
+    ///     fn head(&self, _url: &str) -> Result<EnclosureMetadata, Self::Error> {
+    ///         Err(std::io::ErrorKind::NotConnected.into())
+    ///     }
+    /// }
+    ///
+    /// let mut item = Item::default();
+    /// let result = item.fill_enclosure_metadata("http://example.com/episode.mp3", &Offline);
+    /// assert!(result.is_err());
+    ///
+    /// let enclosure = item.enclosure().unwrap();
+    /// assert_eq!(enclosure.length(), "0");
+    /// assert_eq!(enclosure.mime_type(), "audio/mpeg");
+    /// ```
+    pub fn fill_enclosure_metadata<V, C>(&mut self, url: V, client: &C) -> Result<(), C::Error>
+    where
+        V: Into<String>,
+        C: HeadClient,
+    {
+        let url = url.into();
+        let (metadata, result) = match client.head(&url) {
+            Ok(metadata) => (metadata, Ok(())),
+            Err(err) => (EnclosureMetadata::default(), Err(err)),
+        };
+        self.enclosure = Some(Enclosure::from_metadata(url, metadata));
+        result
+    }
+
     /// Return the GUID for this item.
     ///
     /// # Examples
@@ -367,27 +555,123 @@ impl Item {
     /// item.set_pub_date("Sun, 01 Jan 2017 12:00:00 GMT".to_string());
     /// assert_eq!(item.pub_date(), Some("Sun, 01 Jan 2017 12:00:00 GMT"));
     /// ```
+    pub fn set_pub_date<V>(&mut self, pub_date: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.pub_date = pub_date.into();
+    }
+
+    /// Set the publication date of this item from a `DateTime`, formatted as an RFC822
+    /// timestamp.
+    ///
+    /// # Examples
     ///
-    /// ## Using chrono::DateTime
     /// ```
-    /// # #[cfg(feature = "validation")]
-    /// # {
     /// use rss::Item;
     /// use chrono::{FixedOffset, TimeZone, Utc};
     ///
     /// let mut item = Item::default();
-    /// item.set_pub_date(Utc.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap().to_rfc2822());
+    /// item.set_pub_datetime(Utc.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap());
     /// assert_eq!(item.pub_date(), Some("Sun, 1 Jan 2017 12:00:00 +0000"));
     ///
-    /// item.set_pub_date(FixedOffset::east_opt(2 * 3600).unwrap().with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap().to_rfc2822());
+    /// let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+    /// item.set_pub_datetime(offset.with_ymd_and_hms(2017, 1, 1, 12, 0, 0).unwrap());
     /// assert_eq!(item.pub_date(), Some("Sun, 1 Jan 2017 12:00:00 +0200"));
-    /// # }
     /// ```
-    pub fn set_pub_date<V>(&mut self, pub_date: V)
+    #[cfg(feature = "validation")]
+    pub fn set_pub_datetime<Tz>(&mut self, pub_date: chrono::DateTime<Tz>)
     where
-        V: Into<Option<String>>,
+        Tz: chrono::TimeZone,
+        Tz::Offset: std::fmt::Display,
     {
-        self.pub_date = pub_date.into();
+        self.pub_date = Some(pub_date.to_rfc2822());
+    }
+
+    /// Parse the publication date of this item, accepting the named time zones listed in
+    /// [`parse_rfc822_date`](crate::validation::parse_rfc822_date).
+    ///
+    /// Returns `None` if the item has no publication date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    ///
+    /// let mut item = Item::default();
+    /// item.set_pub_date("Sun, 01 Jan 2017 12:00:00 EST".to_string());
+    /// let date = item.pub_date_parsed().unwrap().unwrap();
+    /// assert_eq!(date.to_rfc3339(), "2017-01-01T12:00:00-05:00");
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn pub_date_parsed(
+        &self,
+    ) -> Option<Result<chrono::DateTime<chrono::FixedOffset>, chrono::ParseError>> {
+        self.pub_date().map(crate::validation::parse_rfc822_date)
+    }
+
+    /// Return when this item was published, from whichever date it carries.
+    ///
+    /// The dates are tried in this order, skipping any that cannot be parsed:
+    ///
+    /// 1. `pubDate`, accepting the named time zones listed in
+    ///    [`parse_rfc822_date`](crate::validation::parse_rfc822_date)
+    /// 2. the first `dc:date`
+    /// 3. `atom:published`
+    ///
+    /// Each date may be either an RFC 822 or an RFC 3339 timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::extension::dublincore::DublinCoreExtension;
+    /// use rss::Item;
+    ///
+    /// let mut dublin_core = DublinCoreExtension::default();
+    /// dublin_core.set_dates(vec!["2017-01-01T12:00:00+02:00".to_string()]);
+    ///
+    /// let mut item = Item::default();
+    /// item.set_dublin_core_ext(dublin_core);
+    /// assert_eq!(
+    ///     item.published().unwrap().to_rfc3339(),
+    ///     "2017-01-01T12:00:00+02:00"
+    /// );
+    ///
+    /// item.set_pub_date("Sun, 01 Jan 2017 12:00:00 EST".to_string());
+    /// assert_eq!(
+    ///     item.published().unwrap().to_rfc3339(),
+    ///     "2017-01-01T12:00:00-05:00"
+    /// );
+    /// ```
+    #[cfg(any(feature = "validation", feature = "aggregate"))]
+    pub fn published(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        use crate::date::parse_date;
+
+        self.pub_date()
+            .and_then(parse_date)
+            .or_else(|| {
+                self.dublin_core_ext()
+                    .and_then(|ext| ext.dates().first())
+                    .and_then(|date| parse_date(date))
+            })
+            .or_else(|| self.atom_published().and_then(parse_date))
+    }
+
+    #[cfg(all(any(feature = "validation", feature = "aggregate"), feature = "atom"))]
+    fn atom_published(&self) -> Option<&str> {
+        self.atom_ext().and_then(|ext| ext.published())
+    }
+
+    #[cfg(all(
+        any(feature = "validation", feature = "aggregate"),
+        not(feature = "atom")
+    ))]
+    fn atom_published(&self) -> Option<&str> {
+        self.extensions()
+            .get("atom")
+            .and_then(|map| map.get("published"))
+            .and_then(|published| published.first())
+            .and_then(|published| published.value())
     }
 
     /// Return the source URL for this item.
@@ -424,6 +708,9 @@ impl Item {
 
     /// Return the content of this item.
     ///
+    /// When the source item contains several `content:encoded` elements, this is the first
+    /// non-empty one.
+    ///
     /// # Examples
     ///
     /// ```
@@ -454,6 +741,61 @@ impl Item {
         self.content = content.into();
     }
 
+    /// Return the `xml:lang` attribute of this item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    ///
+    /// let mut item = Item::default();
+    /// item.set_xml_lang("fr".to_string());
+    /// assert_eq!(item.xml_lang(), Some("fr"));
+    /// ```
+    pub fn xml_lang(&self) -> Option<&str> {
+        self.xml_lang.as_deref()
+    }
+
+    /// Set the `xml:lang` attribute of this item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    ///
+    /// let mut item = Item::default();
+    /// item.set_xml_lang("fr".to_string());
+    /// ```
+    pub fn set_xml_lang<V>(&mut self, xml_lang: V)
+    where
+        V: Into<Option<String>>,
+    {
+        self.xml_lang = xml_lang.into();
+    }
+
+    /// Return a plain text summary of this item of at most `max_chars` characters.
+    ///
+    /// The summary is made from the description, or the content if there is no description.
+    /// HTML tags are removed and entities decoded: those of XML, `&nbsp;` and numeric ones, or
+    /// every HTML5 entity with the `html-entities` feature. Text longer than `max_chars` is cut
+    /// at a word boundary and ends with an ellipsis (`…`), which counts towards the limit.
+    /// Returns an empty string if the item has neither description nor content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    ///
+    /// let mut item = Item::default();
+    /// item.set_description("<p>Fish &amp; <b>chips</b> for dinner</p>".to_string());
+    /// assert_eq!(item.summary(100), "Fish & chips for dinner");
+    /// assert_eq!(item.summary(15), "Fish & chips\u{2026}");
+    /// ```
+    pub fn summary(&self, max_chars: usize) -> String {
+        let html = self.description().or(self.content()).unwrap_or_default();
+        truncate_words(&html_to_text(html), max_chars)
+    }
+
     /// Return the Atom extension for this item.
     ///
     /// # Examples
@@ -558,6 +900,93 @@ impl Item {
         self.dublin_core_ext = dublin_core_ext.into();
     }
 
+    /// Return the Media RSS extension for this item.
+    ///
+    /// The extension holds the `media:content` elements in the Media RSS namespace, whatever
+    /// prefix the feed bound to it. Other Media RSS elements are kept in the extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    /// use rss::extension::media::{MediaContent, MediaExtension};
+    ///
+    /// let mut item = Item::default();
+    /// assert!(item.media_ext().is_none());
+    ///
+    /// let content = MediaContent {
+    ///     url: Some("http://example.com/video.mp4".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// item.set_media_ext(MediaExtension { contents: vec![content] });
+    /// assert_eq!(
+    ///     item.media_ext().unwrap().contents()[0].url(),
+    ///     Some("http://example.com/video.mp4")
+    /// );
+    /// ```
+    pub fn media_ext(&self) -> Option<&media::MediaExtension> {
+        self.media_ext.as_ref()
+    }
+
+    /// Set the Media RSS extension for this item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    /// use rss::extension::media::MediaExtension;
+    ///
+    /// let mut item = Item::default();
+    /// item.set_media_ext(MediaExtension::default());
+    /// ```
+    pub fn set_media_ext<V>(&mut self, media_ext: V)
+    where
+        V: Into<Option<media::MediaExtension>>,
+    {
+        self.media_ext = media_ext.into();
+    }
+
+    /// Return the URL of a transcript of this item from `<podcast:transcript>`.
+    ///
+    /// Transcripts are looked up under the prefix the item's namespaces bind to the
+    /// [Podcasting 2.0](https://podcastindex.org/namespace/1.0) namespace, or under `podcast` if
+    /// that prefix is not bound. The iTunes namespace has no transcript element. If there are
+    /// several transcripts the first one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = r#"
+    /// <rss version="2.0" xmlns:podcast="https://podcastindex.org/namespace/1.0">
+    ///     <channel>
+    ///         <item>
+    ///             <podcast:transcript url="https://example.com/1.vtt" type="text/vtt"/>
+    ///         </item>
+    ///     </channel>
+    /// </rss>"#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    /// assert_eq!(channel.items()[0].transcript_url(), Some("https://example.com/1.vtt"));
+    /// ```
+    pub fn transcript_url(&self) -> Option<&str> {
+        let prefix = self
+            .namespaces
+            .iter()
+            .find(|(_, uri)| *uri == PODCAST_NAMESPACE)
+            .map(|(prefix, _)| prefix.as_str())
+            .or_else(|| (!self.namespaces.contains_key("podcast")).then_some("podcast"))?;
+        self.extensions
+            .get(prefix)
+            .and_then(|map| map.get("transcript"))
+            .and_then(|transcripts| {
+                transcripts
+                    .iter()
+                    .find_map(|transcript| transcript.attrs().get("url"))
+            })
+            .map(String::as_str)
+    }
+
     /// Return the extensions for this item.
     ///
     /// # Examples
@@ -604,6 +1033,198 @@ impl Item {
     {
         self.extensions = extensions.into();
     }
+
+    /// Return the namespaces of the prefixes used by the extensions of this item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Channel;
+    ///
+    /// let input = r#"
+    /// <rss version="2.0" xmlns:ext="http://example.com/ext">
+    ///     <channel>
+    ///         <item><ext:rating>5</ext:rating></item>
+    ///     </channel>
+    /// </rss>"#;
+    /// let channel = input.parse::<Channel>().unwrap();
+    /// assert_eq!(
+    ///     channel.items()[0].namespaces().get("ext").map(String::as_str),
+    ///     Some("http://example.com/ext")
+    /// );
+    /// ```
+    pub fn namespaces(&self) -> &BTreeMap<String, String> {
+        &self.namespaces
+    }
+
+    /// Set the namespaces of the prefixes used by the extensions of this item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use rss::Item;
+    ///
+    /// let mut item = Item::default();
+    /// item.set_namespaces(BTreeMap::new());
+    /// ```
+    pub fn set_namespaces<V>(&mut self, namespaces: V)
+    where
+        V: Into<BTreeMap<String, String>>,
+    {
+        self.namespaces = namespaces.into();
+    }
+
+    /// Generate a GUID for this item if it does not have one and return the item's GUID.
+    ///
+    /// The generated GUID is not a permalink. Its value is the 64-bit
+    /// [FNV-1a](http://www.isthe.com/chongo/tech/comp/fnv/) hash of the item's link, title and
+    /// publication date joined with newlines (missing fields are treated as empty), formatted as
+    /// 16 lowercase hexadecimal digits. The same fields always produce the same GUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    ///
+    /// let mut item = Item::default();
+    /// item.set_link("http://example.com/post".to_string());
+    /// item.set_title("Post".to_string());
+    ///
+    /// let guid = item.ensure_guid().clone();
+    /// assert!(!guid.is_permalink());
+    /// assert_eq!(guid.value().len(), 16);
+    ///
+    /// let mut other = Item::default();
+    /// other.set_link("http://example.com/post".to_string());
+    /// other.set_title("Post".to_string());
+    /// assert_eq!(other.ensure_guid(), &guid);
+    /// ```
+    pub fn ensure_guid(&mut self) -> &Guid {
+        let link = self.link.as_deref().unwrap_or_default();
+        let title = self.title.as_deref().unwrap_or_default();
+        let pub_date = self.pub_date.as_deref().unwrap_or_default();
+        self.guid.get_or_insert_with(|| {
+            let hash = fnv1a_64([link, title, pub_date].join("\n").as_bytes());
+            Guid {
+                value: format!("{:016x}", hash),
+                permalink: false,
+                extras: Default::default(),
+            }
+        })
+    }
+
+    /// Compare this item with another version of it and return the fields that differ.
+    ///
+    /// Fields are identified by their name in `Item`, such as `"title"` or `"enclosure"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Item;
+    ///
+    /// let mut previous = Item::default();
+    /// previous.set_title("Title".to_string());
+    /// previous.set_link("http://example.com/post".to_string());
+    ///
+    /// let mut current = previous.clone();
+    /// current.set_title("Updated Title".to_string());
+    ///
+    /// let diff = current.diff(&previous);
+    /// assert_eq!(diff.fields(), ["title"]);
+    /// assert!(diff.contains("title"));
+    /// assert!(current.diff(&current).is_empty());
+    /// ```
+    pub fn diff(&self, other: &Item) -> ItemDiff {
+        let mut fields = Vec::new();
+        let mut compare = |name: &'static str, changed: bool| {
+            if changed {
+                fields.push(name);
+            }
+        };
+
+        compare("title", self.title != other.title);
+        compare("link", self.link != other.link);
+        compare("description", self.description != other.description);
+        compare("author", self.author != other.author);
+        compare("categories", self.categories != other.categories);
+        compare("comments", self.comments != other.comments);
+        compare("enclosure", self.enclosure != other.enclosure);
+        compare("guid", self.guid != other.guid);
+        compare("pub_date", self.pub_date != other.pub_date);
+        compare("source", self.source != other.source);
+        compare("content", self.content != other.content);
+        compare("xml_lang", self.xml_lang != other.xml_lang);
+        compare("extensions", self.extensions != other.extensions);
+        #[cfg(feature = "atom")]
+        compare("atom_ext", self.atom_ext != other.atom_ext);
+        compare("itunes_ext", self.itunes_ext != other.itunes_ext);
+        compare(
+            "dublin_core_ext",
+            self.dublin_core_ext != other.dublin_core_ext,
+        );
+
+        ItemDiff { fields }
+    }
+}
+
+#[cfg(feature = "atom")]
+impl Item {
+    /// Populate the enclosure from an `atom:link` with `rel="enclosure"` if the item has no
+    /// enclosure.
+    pub(crate) fn promote_atom_enclosure(&mut self) {
+        if self.enclosure.is_some() {
+            return;
+        }
+        let link = self
+            .atom_ext
+            .as_ref()
+            .and_then(|ext| ext.links().iter().find(|link| link.rel() == "enclosure"));
+        if let Some(link) = link {
+            self.enclosure = Some(Enclosure {
+                url: link.href().to_string(),
+                length: link.length().unwrap_or("0").to_string(),
+                mime_type: link.mime_type().unwrap_or_default().to_string(),
+            });
+        }
+    }
+
+    /// Populate the GUID from `atom:id` as a non-permalink if the item has no GUID.
+    pub(crate) fn promote_atom_id(&mut self) {
+        if self.guid.is_some() {
+            return;
+        }
+        if let Some(id) = self.atom_ext.as_ref().and_then(|ext| ext.id()) {
+            self.guid = Some(Guid {
+                value: id.to_string(),
+                permalink: false,
+                extras: Default::default(),
+            });
+        }
+    }
+}
+
+/// The fields that differ between two versions of an item, as returned by `Item::diff`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ItemDiff {
+    fields: Vec<&'static str>,
+}
+
+impl ItemDiff {
+    /// Return the names of the fields that differ, in declaration order.
+    pub fn fields(&self) -> &[&'static str] {
+        &self.fields
+    }
+
+    /// Return whether the field with the given name differs.
+    pub fn contains(&self, field: &str) -> bool {
+        self.fields.contains(&field)
+    }
+
+    /// Return whether no field differs.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
 }
 
 impl Item {
@@ -612,16 +1233,29 @@ impl Item {
         namespaces: &BTreeMap<String, String>,
         reader: &mut Reader<R>,
         atts: Attributes,
+    ) -> Result<Self, Error> {
+        Item::from_xml_with_options(namespaces, reader, atts, &ReadOptions::default())
+    }
+
+    pub(crate) fn from_xml_with_options<R: BufRead>(
+        namespaces: &BTreeMap<String, String>,
+        reader: &mut Reader<R>,
+        atts: Attributes,
+        options: &ReadOptions,
     ) -> Result<Self, Error> {
         let mut item = Item::default();
         let mut extensions = ExtensionMap::new();
         let mut buf = Vec::new();
 
+        item.xml_lang = xml_lang(reader, atts.clone())?;
         let namespaces = read_namespace_declarations(reader, atts, namespaces)?;
 
         loop {
             match reader.read_event_into(&mut buf)? {
-                Event::Start(element) => match decode(element.name().as_ref(), reader)?.as_ref() {
+                Event::Start(element) => match core_element_name(
+                    decode(element.name().as_ref(), reader)?.as_ref(),
+                    &namespaces,
+                ) {
                     "category" => {
                         let category = Category::from_xml(reader, element.attributes())?;
                         item.categories.push(category);
@@ -635,17 +1269,24 @@ impl Item {
                         let source = Source::from_xml(reader, element.attributes())?;
                         item.source = Some(source);
                     }
-                    "title" => item.title = element_text(reader)?,
+                    "title" => item.title = element_text_with(reader, options)?,
                     "link" => {
-                        if let Some(link) = element_text(reader)?.filter(|text| !text.is_empty()) {
+                        if let Some(link) =
+                            element_text_with(reader, options)?.filter(|text| !text.is_empty())
+                        {
                             item.link = Some(link);
                         }
                     }
-                    "description" => item.description = element_text(reader)?,
-                    "author" => item.author = element_text(reader)?,
-                    "comments" => item.comments = element_text(reader)?,
-                    "pubDate" => item.pub_date = element_text(reader)?,
-                    "content:encoded" => item.content = element_text(reader)?,
+                    "description" => item.description = element_text_with(reader, options)?,
+                    "author" => item.author = element_text_with(reader, options)?,
+                    "comments" => item.comments = element_text_with(reader, options)?,
+                    "pubDate" => item.pub_date = element_text_with(reader, options)?,
+                    "content:encoded" => {
+                        let content = element_text_with(reader, options)?;
+                        if item.content.is_none() {
+                            item.content = content;
+                        }
+                    }
                     n => {
                         if let Some((prefix, name)) = extension_name(n) {
                             let scope_namespases = read_namespace_declarations(
@@ -667,7 +1308,32 @@ impl Item {
                                 Some(ns @ dublincore::NAMESPACE) => {
                                     extension_entry(&mut extensions, ns, name).push(ext);
                                 }
-                                _ => extension_entry(&mut item.extensions, prefix, name).push(ext),
+                                Some(ns)
+                                    if is_media_namespace(ns)
+                                        && media::MediaExtension::handles(name) =>
+                                {
+                                    record_namespaces(
+                                        &mut item.namespaces,
+                                        &ext,
+                                        &scope_namespases,
+                                    );
+                                    extension_entry(&mut extensions, media::NAMESPACE, name)
+                                        .push(ext);
+                                }
+                                ns => {
+                                    let consumed = options.on_unknown_element.as_ref().is_some_and(
+                                        |handler| handler.call(ns.unwrap_or(""), &ext),
+                                    );
+                                    if !consumed {
+                                        record_namespaces(
+                                            &mut item.namespaces,
+                                            &ext,
+                                            &scope_namespases,
+                                        );
+                                        extension_entry(&mut item.extensions, prefix, name)
+                                            .push(ext);
+                                    }
+                                }
                             }
                         } else {
                             skip(element.name(), reader)?;
@@ -692,16 +1358,97 @@ impl Item {
         if let Some(v) = extensions.remove(dublincore::NAMESPACE) {
             item.dublin_core_ext = Some(dublincore::DublinCoreExtension::from_map(v))
         }
+        if let Some(v) = extensions.remove(media::NAMESPACE) {
+            item.media_ext = Some(media::MediaExtension::from_map(v))
+        }
 
         Ok(item)
     }
 }
 
-impl ToXml for Item {
-    fn to_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), XmlError> {
-        let name = "item";
+impl Item {
+    /// Moves the extensions under the `from` prefix to the `to` prefix, both bound to `uri` by the
+    /// channel. An item that binds either prefix to another namespace is left unchanged.
+    pub(crate) fn rename_prefix(&mut self, from: &str, to: &str, uri: &str) {
+        let bound_elsewhere = |prefix: &str| {
+            self.namespaces
+                .get(prefix)
+                .is_some_and(|bound| bound != uri)
+        };
+        if bound_elsewhere(from) || bound_elsewhere(to) {
+            return;
+        }
+        if self.namespaces.remove(from).is_some() {
+            self.namespaces.insert(to.to_string(), uri.to_string());
+        }
+        rename_extensions(&mut self.extensions, from, to);
+    }
+
+    /// Returns whether the item has the title or the description that RSS requires. The HTML
+    /// content (`content:encoded`) and a Dublin Core description stand in for the description.
+    pub(crate) fn has_title_or_description(&self) -> bool {
+        self.title.is_some()
+            || self.description.is_some()
+            || self.content.is_some()
+            || self
+                .dublin_core_ext
+                .as_ref()
+                .is_some_and(|ext| !ext.descriptions().is_empty())
+    }
 
-        writer.write_event(Event::Start(BytesStart::new(name)))?;
+    /// The prefix the item's namespaces bind to Media RSS, or else the first free one of
+    /// `media`, `media1`, `media2` and so on.
+    fn media_prefix(&self) -> Cow<'_, str> {
+        if let Some((prefix, _)) = self
+            .namespaces
+            .iter()
+            .find(|(_, uri)| is_media_namespace(uri))
+        {
+            return Cow::Borrowed(prefix);
+        }
+        let mut prefix = Cow::Borrowed(media::PREFIX);
+        let mut n = 0;
+        while self.namespaces.contains_key(prefix.as_ref()) {
+            n += 1;
+            prefix = Cow::Owned(format!("{}{}", media::PREFIX, n));
+        }
+        prefix
+    }
+
+    /// The declaration of the Media RSS prefix, if the item has the extension and its namespaces
+    /// do not declare it.
+    fn media_declaration(&self) -> Option<(String, String)> {
+        self.media_ext.as_ref()?;
+        let prefix = self.media_prefix();
+        (!self.namespaces.contains_key(prefix.as_ref()))
+            .then(|| (prefix.into_owned(), media::NAMESPACE.to_string()))
+    }
+
+    /// Writes the item. `scope` holds the namespaces declared by the channel, a prefix of the
+    /// item that is bound to another namespace there is declared again on the item element.
+    pub(crate) fn write_item<W: Write>(
+        &self,
+        writer: &mut Writer<W>,
+        scope: &BTreeMap<String, String>,
+        options: &WriteOptions,
+    ) -> Result<(), XmlError> {
+        let name = options.item_element_name;
+
+        let mut element = BytesStart::new(name);
+        if let Some(xml_lang) = self.xml_lang.as_ref() {
+            element.push_attribute(("xml:lang", xml_lang.as_str()));
+        }
+        let media_declaration = self.media_declaration();
+        for (prefix, uri) in self
+            .namespaces
+            .iter()
+            .chain(media_declaration.as_ref().map(|(p, u)| (p, u)))
+        {
+            if scope.get(prefix) != Some(uri) {
+                element.push_attribute((format!("xmlns:{}", prefix).as_str(), uri.as_str()));
+            }
+        }
+        writer.write_event(Event::Start(element))?;
 
         if let Some(title) = self.title.as_ref() {
             writer.write_text_element("title", title)?;
@@ -712,7 +1459,7 @@ impl ToXml for Item {
         }
 
         if let Some(description) = self.description.as_ref() {
-            writer.write_cdata_element("description", description)?;
+            write_text_or_cdata_element(writer, "description", description, options)?;
         }
 
         if let Some(author) = self.author.as_ref() {
@@ -734,7 +1481,7 @@ impl ToXml for Item {
         }
 
         if let Some(pub_date) = self.pub_date.as_ref() {
-            writer.write_text_element("pubDate", pub_date)?;
+            writer.write_text_element("pubDate", options.format_date(pub_date))?;
         }
 
         if let Some(source) = self.source.as_ref() {
@@ -742,7 +1489,7 @@ impl ToXml for Item {
         }
 
         if let Some(content) = self.content.as_ref() {
-            writer.write_cdata_element("content:encoded", content)?;
+            write_text_or_cdata_element(writer, "content:encoded", content, options)?;
         }
 
         for map in self.extensions.values() {
@@ -766,9 +1513,32 @@ impl ToXml for Item {
             ext.to_xml(writer)?;
         }
 
+        if let Some(ext) = self.media_ext.as_ref() {
+            ext.to_xml_with_prefix(&self.media_prefix(), writer)?;
+        }
+
         writer.write_event(Event::End(BytesEnd::new(name)))?;
         Ok(())
     }
+}
+
+fn write_text_or_cdata_element<W: Write>(
+    writer: &mut Writer<W>,
+    name: &str,
+    text: &str,
+    options: &WriteOptions,
+) -> Result<(), XmlError> {
+    if options.cdata_fields.contains(name) {
+        writer.write_cdata_element(name, text)
+    } else {
+        writer.write_text_element(name, text)
+    }
+}
+
+impl ToXml for Item {
+    fn to_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), XmlError> {
+        self.write_item(writer, &self.namespaces, &WriteOptions::default())
+    }
 
     fn used_namespaces(&self) -> BTreeMap<String, String> {
         let mut namespaces = BTreeMap::new();
@@ -784,14 +1554,67 @@ impl ToXml for Item {
         if let Some(ext) = self.dublin_core_ext() {
             namespaces.extend(ext.used_namespaces());
         }
+        namespaces.extend(self.media_declaration());
         #[cfg(feature = "atom")]
         if let Some(ext) = self.atom_ext() {
             namespaces.extend(ext.used_namespaces());
         }
+        #[cfg(not(feature = "atom"))]
+        namespaces.extend(atom_namespace_declaration(&self.extensions));
+        namespaces.extend(self.namespaces.clone());
         namespaces
     }
 }
 
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        // the namespaces and the raw source are left out, an item read from a feed equals the same
+        // item built in code
+        let Item {
+            title,
+            link,
+            description,
+            author,
+            categories,
+            comments,
+            enclosure,
+            guid,
+            pub_date,
+            source,
+            content,
+            xml_lang,
+            extensions,
+            #[cfg(feature = "atom")]
+            atom_ext,
+            itunes_ext,
+            dublin_core_ext,
+            media_ext,
+            namespaces: _,
+            raw_xml: _,
+        } = self;
+        #[cfg(feature = "atom")]
+        if *atom_ext != other.atom_ext {
+            return false;
+        }
+        *title == other.title
+            && *link == other.link
+            && *description == other.description
+            && *author == other.author
+            && *categories == other.categories
+            && *comments == other.comments
+            && *enclosure == other.enclosure
+            && *guid == other.guid
+            && *pub_date == other.pub_date
+            && *source == other.source
+            && *content == other.content
+            && *xml_lang == other.xml_lang
+            && *extensions == other.extensions
+            && *itunes_ext == other.itunes_ext
+            && *dublin_core_ext == other.dublin_core_ext
+            && *media_ext == other.media_ext
+    }
+}
+
 #[cfg(feature = "builders")]
 impl ItemBuilder {
     /// Builds a new `Item`.
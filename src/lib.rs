@@ -94,7 +94,7 @@ extern crate derive_builder;
 extern crate quick_xml;
 
 #[cfg(feature = "serde")]
-#[cfg(feature = "validation")]
+#[cfg(any(feature = "validation", feature = "aggregate"))]
 extern crate chrono;
 #[cfg(feature = "validation")]
 extern crate mime;
@@ -107,13 +107,20 @@ extern crate url;
 mod category;
 mod channel;
 mod cloud;
+mod declaration;
 mod enclosure;
 mod guid;
 mod image;
 mod item;
+mod lint;
+mod options;
 mod source;
 mod textinput;
 
+#[cfg(any(feature = "validation", feature = "aggregate"))]
+mod date;
+#[cfg(feature = "html-entities")]
+mod entities;
 mod error;
 mod toxml;
 mod util;
@@ -125,27 +132,37 @@ pub mod extension;
 #[cfg(feature = "validation")]
 pub mod validation;
 
+/// Types for combining the items of several RSS feeds.
+#[cfg(feature = "aggregate")]
+pub mod aggregate;
+
 pub use crate::category::Category;
 #[cfg(feature = "builders")]
 pub use crate::category::CategoryBuilder;
-pub use crate::channel::Channel;
 #[cfg(feature = "builders")]
 pub use crate::channel::ChannelBuilder;
-pub use crate::cloud::Cloud;
+pub use crate::channel::{Channel, ChannelDiff, ChannelMetadata, FeedFormat};
 #[cfg(feature = "builders")]
 pub use crate::cloud::CloudBuilder;
-pub use crate::enclosure::Enclosure;
+pub use crate::cloud::{Cloud, CloudProtocol};
+pub use crate::declaration::XmlDeclaration;
 #[cfg(feature = "builders")]
 pub use crate::enclosure::EnclosureBuilder;
+pub use crate::enclosure::{Enclosure, EnclosureMetadata, HeadClient};
 pub use crate::guid::Guid;
 #[cfg(feature = "builders")]
 pub use crate::guid::GuidBuilder;
 pub use crate::image::Image;
 #[cfg(feature = "builders")]
 pub use crate::image::ImageBuilder;
-pub use crate::item::Item;
 #[cfg(feature = "builders")]
 pub use crate::item::ItemBuilder;
+pub use crate::item::{Item, ItemDiff};
+pub use crate::lint::Lint;
+pub use crate::options::{
+    DateFormat, ElementHandler, EntitySet, Escape, Indent, ItemOrder, NamespacePlacement,
+    ReadOptions, WriteOptions,
+};
 pub use crate::source::Source;
 #[cfg(feature = "builders")]
 pub use crate::source::SourceBuilder;
@@ -0,0 +1,80 @@
+// This file is part of rss.
+//
+// Copyright © 2015-2021 The rust-syndication Developers
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the MIT License and/or Apache 2.0 License.
+
+use std::fmt;
+
+use crate::channel::Channel;
+use crate::extension::util::ATOM_NAMESPACE;
+
+/// An advisory finding about a channel that does not prevent it from being used.
+///
+/// Items are identified by their zero-based index in `Channel::items`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lint {
+    /// The channel has no `atom:link` with `rel="self"` pointing to the feed.
+    MissingAtomSelfLink,
+    /// The item has neither a title nor a description, while RSS requires one of them.
+    ItemWithoutTitleOrDescription(usize),
+    /// The item has no GUID.
+    ItemWithoutGuid(usize),
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Lint::MissingAtomSelfLink => {
+                write!(f, "channel has no atom:link with rel=\"self\"")
+            }
+            Lint::ItemWithoutTitleOrDescription(index) => write!(
+                f,
+                "item {} has neither title nor description (RSS requires one)",
+                index
+            ),
+            Lint::ItemWithoutGuid(index) => write!(f, "item {} has no guid", index),
+        }
+    }
+}
+
+/// Returns the lints of `channel`.
+pub(crate) fn lint(channel: &Channel) -> Vec<Lint> {
+    let mut lints = Vec::new();
+
+    if !has_atom_self_link(channel) {
+        lints.push(Lint::MissingAtomSelfLink);
+    }
+
+    for (index, item) in channel.items().iter().enumerate() {
+        if !item.has_title_or_description() {
+            lints.push(Lint::ItemWithoutTitleOrDescription(index));
+        }
+        if item.guid().is_none() {
+            lints.push(Lint::ItemWithoutGuid(index));
+        }
+    }
+
+    lints
+}
+
+fn has_atom_self_link(channel: &Channel) -> bool {
+    #[cfg(feature = "atom")]
+    if let Some(ext) = channel.atom_ext() {
+        if ext.links().iter().any(|link| link.rel() == "self") {
+            return true;
+        }
+    }
+
+    let prefix = channel.has_namespace(ATOM_NAMESPACE).unwrap_or("atom");
+    channel
+        .extensions()
+        .get(prefix)
+        .and_then(|map| map.get("link"))
+        .is_some_and(|links| {
+            links
+                .iter()
+                .any(|link| link.attrs().get("rel").map(String::as_str) == Some("self"))
+        })
+}
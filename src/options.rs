@@ -0,0 +1,295 @@
+// This file is part of rss.
+//
+// Copyright © 2015-2021 The rust-syndication Developers
+//
+// This program is free software; you can redistribute it and/or modify
+// it under the terms of the MIT License and/or Apache 2.0 License.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::extension::Extension;
+
+/// Where namespace declarations are emitted when writing a channel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NamespacePlacement {
+    /// Declare every namespace on the root `<rss>` element.
+    #[default]
+    Root,
+    /// Declare each namespace on the outermost element that uses its prefix.
+    Element,
+}
+
+/// The order in which the items of a channel are written.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ItemOrder {
+    /// Write the items in the order they are stored in the channel.
+    #[default]
+    AsStored,
+    /// Write the items in reverse order, for example to emit oldest-first items newest-first.
+    Reverse,
+}
+
+/// The format dates are written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateFormat {
+    /// RFC 2822, e.g. `Sun, 1 Jan 2017 12:00:00 +0000`, as used by RSS.
+    Rfc2822,
+    /// RFC 3339, e.g. `2017-01-01T12:00:00+00:00`.
+    Rfc3339,
+}
+
+/// The characters escaped in text when writing a channel. Attribute values always escape
+/// quotes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    /// Escape `&`, `<`, `>`, `'` and `"`.
+    #[default]
+    Full,
+    /// Escape only `&`, `<` and `>`, leaving quotes as written.
+    Partial,
+}
+
+/// The named entities that are resolved in text when reading a channel.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EntitySet {
+    /// Resolve only the predefined XML entities `&lt;`, `&gt;`, `&amp;`, `&apos;` and `&quot;`.
+    #[default]
+    Xml,
+    /// Resolve every named character reference of HTML5, such as `&nbsp;` and `&mdash;`.
+    /// Without the `html-entities` feature only the predefined XML entities are resolved.
+    Html5,
+}
+
+/// The indentation used when pretty printing a channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Indent {
+    /// Indent each level with the given number of spaces.
+    Spaces(usize),
+    /// Indent each level with the given number of tabs.
+    Tabs(usize),
+}
+
+impl Indent {
+    /// Returns the indentation character and the number of times it is repeated per level.
+    pub(crate) fn char_and_size(self) -> (u8, usize) {
+        match self {
+            Indent::Spaces(n) => (b' ', n),
+            Indent::Tabs(n) => (b'\t', n),
+        }
+    }
+}
+
+/// Options that control how a channel is written.
+///
+/// # Examples
+///
+/// ```
+/// use rss::{Channel, NamespacePlacement, WriteOptions};
+///
+/// let options = WriteOptions {
+///     namespace_placement: NamespacePlacement::Element,
+///     ..Default::default()
+/// };
+/// let channel = Channel::default();
+/// channel.write_to_with(::std::io::sink(), &options).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WriteOptions {
+    /// Where namespace declarations are emitted. Defaults to `NamespacePlacement::Root`.
+    pub namespace_placement: NamespacePlacement,
+    /// The order in which items are written. Defaults to `ItemOrder::AsStored`.
+    pub item_order: ItemOrder,
+    /// The item elements whose text is written as CDATA rather than escaped text. Only
+    /// `description` and `content:encoded` are affected. Defaults to both.
+    pub cdata_fields: HashSet<&'static str>,
+    /// The format `pubDate` and `lastBuildDate` are written in. Dates that cannot be parsed
+    /// as RFC 822 or RFC 3339 are written unchanged. Defaults to `None`, which writes every
+    /// date unchanged. Has no effect without the `validation` feature.
+    pub date_format: Option<DateFormat>,
+    /// The characters escaped in text. Defaults to `Escape::Full`.
+    pub escape: Escape,
+    /// Write the XML declaration before the `<rss>` element. Defaults to `true`.
+    pub write_declaration: bool,
+    /// The indentation used to pretty print the channel. Defaults to `None`, which writes the
+    /// channel without whitespace between elements.
+    pub indent: Option<Indent>,
+    /// The name of the element each item is written as. The result is not RSS unless this is
+    /// `item`, and is not read back as items by this crate. Defaults to `"item"`.
+    pub item_element_name: &'static str,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        WriteOptions {
+            namespace_placement: NamespacePlacement::default(),
+            item_order: ItemOrder::default(),
+            cdata_fields: ["description", "content:encoded"].into_iter().collect(),
+            date_format: None,
+            escape: Escape::default(),
+            write_declaration: true,
+            indent: None,
+            item_element_name: "item",
+        }
+    }
+}
+
+impl WriteOptions {
+    /// Returns the date to write for a stored date.
+    pub(crate) fn format_date<'a>(&self, date: &'a str) -> Cow<'a, str> {
+        #[cfg(feature = "validation")]
+        if let Some(format) = self.date_format {
+            if let Some(parsed) = crate::date::parse_date(date) {
+                return Cow::Owned(match format {
+                    DateFormat::Rfc2822 => parsed.to_rfc2822(),
+                    DateFormat::Rfc3339 => parsed.to_rfc3339(),
+                });
+            }
+        }
+        Cow::Borrowed(date)
+    }
+}
+
+/// Options that control how a channel is read.
+///
+/// # Examples
+///
+/// ```
+/// use rss::{Channel, ReadOptions};
+///
+/// let options = ReadOptions::default();
+/// let input = r#"<rss version="2.0"><channel></channel></rss>"#;
+/// let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadOptions {
+    /// Trim whitespace from the start and end of each text node before it is joined with the
+    /// surrounding text and CDATA of an element, so `a <![CDATA[b]]>` is read as `ab`. The
+    /// text of an element is trimmed as a whole either way. Defaults to `false`.
+    pub trim_text: bool,
+    /// Populate the enclosure of items that have none from an `atom:link` with
+    /// `rel="enclosure"`. The link's `href` becomes the URL, `type` the MIME type and `length`
+    /// the length. A missing `type` is read as an empty MIME type and a missing `length` as `0`.
+    /// Defaults to `false`. Has no effect without the `atom` feature.
+    pub promote_atom_enclosures: bool,
+    /// Populate the GUID of items that have none from `atom:id`, as a GUID that is not a
+    /// permalink. An explicit `<guid>` always takes precedence. Defaults to `false`. Has no
+    /// effect without the `atom` feature.
+    pub promote_atom_id: bool,
+    /// Return `Error::InvalidValue` if `ttl` is not a positive integer, a skip hour is not
+    /// between 0 and 23, or a skip day is not a day of the week, instead of storing the value.
+    /// Defaults to `false`.
+    pub strict: bool,
+    /// The number of items to reserve space for before reading them, which avoids repeated
+    /// reallocation when reading large feeds. Defaults to `0`.
+    pub expected_items: usize,
+    /// Store the source of each item, from `<item>` to `</item>`, in `Item::raw_xml`. The
+    /// whole document is kept in memory while reading. Defaults to `false`.
+    pub capture_raw_items: bool,
+    /// A handler called for each namespaced element of a channel or item that is not part of
+    /// RSS or of a known extension. Defaults to `None`.
+    pub on_unknown_element: Option<ElementHandler>,
+    /// Keep entity references other than the predefined XML entities, such as `&mdash;`, as
+    /// written in the text of channel and item elements instead of returning an error.
+    /// Character references and the predefined entities are still unescaped. Defaults to
+    /// `false`.
+    pub preserve_unknown_entities: bool,
+    /// The named entities resolved in the text of channel and item elements. Character
+    /// references are resolved with either set. Defaults to `EntitySet::Xml`. `EntitySet::Html5`
+    /// has no effect without the `html-entities` feature.
+    pub entity_set: EntitySet,
+    /// Store the processing instructions before the `<rss>` element, such as
+    /// `xml-stylesheet`, in `Channel::processing_instructions` so they are written back.
+    /// Defaults to `false`.
+    pub preserve_processing_instructions: bool,
+    /// The number of wrapper elements the `<rss>` or `<rdf:RDF>` element may be nested in, as
+    /// in `<response><rss>...</rss></response>`. The first root element found at or above this
+    /// depth is read and everything around it is ignored; elements at this depth that are not
+    /// a root are skipped without searching them. Namespaces declared on the wrappers are in
+    /// scope in the feed and kept in `Channel::namespaces`. Defaults to `0`, which requires the
+    /// root to be the first element of the document.
+    pub root_search_depth: usize,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        ReadOptions {
+            trim_text: false,
+            promote_atom_enclosures: false,
+            promote_atom_id: false,
+            strict: false,
+            expected_items: 0,
+            capture_raw_items: false,
+            on_unknown_element: None,
+            preserve_unknown_entities: false,
+            entity_set: EntitySet::Xml,
+            preserve_processing_instructions: false,
+            root_search_depth: 0,
+        }
+    }
+}
+
+type ElementCallback = dyn FnMut(&str, &Extension) -> bool + Send;
+
+/// A callback for elements that are not recognized while reading.
+///
+/// The callback receives the namespace URI of the element (empty if its prefix is not bound)
+/// and the parsed element. The element is still added to the `extensions` of its channel or
+/// item unless the callback returns `true`.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+///
+/// use rss::{Channel, ElementHandler, ReadOptions};
+///
+/// let names = Arc::new(Mutex::new(Vec::new()));
+/// let seen = names.clone();
+/// let options = ReadOptions {
+///     on_unknown_element: Some(ElementHandler::new(move |_, element| {
+///         seen.lock().unwrap().push(element.name().to_string());
+///         false
+///     })),
+///     ..Default::default()
+/// };
+///
+/// let input = r#"<rss xmlns:foo="http://example.com/foo"><channel><foo:bar/></channel></rss>"#;
+/// let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+/// assert_eq!(*names.lock().unwrap(), ["foo:bar"]);
+/// assert!(channel.extensions().contains_key("foo"));
+/// ```
+#[derive(Clone)]
+pub struct ElementHandler(Arc<Mutex<ElementCallback>>);
+
+impl ElementHandler {
+    /// Creates a handler from a callback that returns whether it consumed the element.
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: FnMut(&str, &Extension) -> bool + Send + 'static,
+    {
+        ElementHandler(Arc::new(Mutex::new(callback)))
+    }
+
+    /// Calls the callback and returns whether it consumed the element.
+    pub(crate) fn call(&self, namespace: &str, element: &Extension) -> bool {
+        match self.0.lock() {
+            Ok(mut callback) => callback(namespace, element),
+            Err(_) => false,
+        }
+    }
+}
+
+impl fmt::Debug for ElementHandler {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ElementHandler").finish()
+    }
+}
+
+impl PartialEq for ElementHandler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
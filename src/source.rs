@@ -15,7 +15,7 @@ use quick_xml::Writer;
 
 use crate::error::Error;
 use crate::toxml::ToXml;
-use crate::util::{attr_value, decode, element_text};
+use crate::util::{attr_value, decode, element_text, looks_like_url};
 
 /// Represents the source of an RSS item.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -69,6 +69,28 @@ impl Source {
         self.url = url.into();
     }
 
+    /// Resolve the URL of this source against a base URL, such as the URL the feed was fetched
+    /// from. Absolute URLs are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::Source;
+    /// use url::Url;
+    ///
+    /// let base = Url::parse("http://example.com/feed.xml").unwrap();
+    /// let mut source = Source::default();
+    /// source.set_url("/other/feed.xml");
+    /// assert_eq!(
+    ///     source.resolved_url(&base).unwrap().as_str(),
+    ///     "http://example.com/other/feed.xml"
+    /// );
+    /// ```
+    #[cfg(feature = "validation")]
+    pub fn resolved_url(&self, base: &url::Url) -> Result<url::Url, url::ParseError> {
+        base.join(self.url())
+    }
+
     /// Return the title of this source.
     ///
     /// # Examples
@@ -104,6 +126,9 @@ impl Source {
 
 impl Source {
     /// Builds a Source from source XML
+    ///
+    /// If the element has no `url` attribute and its text looks like an absolute URL (a scheme
+    /// followed by `://` and no whitespace), the text is used as the URL instead of the title.
     pub fn from_xml<R: BufRead>(
         reader: &mut Reader<R>,
         mut atts: Attributes,
@@ -117,7 +142,12 @@ impl Source {
             }
         }
 
-        source.title = element_text(reader)?;
+        match element_text(reader)? {
+            Some(text) if source.url.is_empty() && looks_like_url(&text) => {
+                source.url = text.trim().to_string();
+            }
+            title => source.title = title,
+        }
         Ok(source)
     }
 }
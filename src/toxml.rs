@@ -7,10 +7,14 @@
 
 use std::collections::BTreeMap;
 use std::io::Write;
+use std::str;
 
 use quick_xml::events::{BytesCData, BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::Error as XmlError;
-use quick_xml::Writer;
+use quick_xml::{Reader, Writer};
+
+use crate::error::Error;
+use crate::options::Escape;
 
 pub trait ToXml {
     fn to_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), XmlError>;
@@ -20,7 +24,7 @@ pub trait ToXml {
     }
 }
 
-impl<'a, T: ToXml> ToXml for &'a T {
+impl<T: ToXml> ToXml for &T {
     fn to_xml<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), XmlError> {
         (*self).to_xml(writer)
     }
@@ -38,6 +42,9 @@ pub trait WriterExt {
         T: AsRef<str>,
         I: IntoIterator<Item = T>;
 
+    /// Writes the text as CDATA. Every `]]>` in the text is split across two adjacent CDATA
+    /// sections, `]]` ending the first and `>` starting the second, so reading the element
+    /// text back yields the original text.
     fn write_cdata_element<N, T>(&mut self, name: N, text: T) -> Result<(), XmlError>
     where
         N: AsRef<str>,
@@ -112,3 +119,78 @@ impl<W: Write> WriterExt for Writer<W> {
         Ok(())
     }
 }
+
+/// Copy a serialized document to `writer`, declaring each namespace of `namespaces` on the
+/// outermost element that uses its prefix and escaping text as given by `escape`.
+pub(crate) fn rewrite_document<W: Write>(
+    document: &[u8],
+    namespaces: &BTreeMap<String, String>,
+    escape: Escape,
+    writer: &mut Writer<W>,
+) -> Result<(), Error> {
+    let mut reader = Reader::from_reader(document);
+    let mut scopes: Vec<Vec<String>> = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(element) => {
+                let (element, declared) = declare_namespaces(element, namespaces, &scopes)?;
+                scopes.push(declared);
+                writer.write_event(Event::Start(element))?;
+            }
+            Event::Empty(element) => {
+                let (element, _) = declare_namespaces(element, namespaces, &scopes)?;
+                writer.write_event(Event::Empty(element))?;
+            }
+            Event::End(element) => {
+                scopes.pop();
+                writer.write_event(Event::End(element))?;
+            }
+            Event::Text(text) if escape == Escape::Partial => {
+                let text = text.unescape()?;
+                let escaped = quick_xml::escape::partial_escape(text.as_ref());
+                writer.write_event(Event::Text(BytesText::from_escaped(escaped)))?;
+            }
+            Event::Eof => break,
+            event => writer.write_event(event)?,
+        }
+        buf.clear();
+    }
+
+    Ok(())
+}
+
+fn declare_namespaces<'a>(
+    mut element: BytesStart<'a>,
+    namespaces: &BTreeMap<String, String>,
+    scopes: &[Vec<String>],
+) -> Result<(BytesStart<'a>, Vec<String>), Error> {
+    let mut declared = Vec::new();
+    let mut used = Vec::new();
+
+    if let Some(prefix) = element.name().prefix() {
+        used.push(str::from_utf8(prefix.as_ref())?.to_string());
+    }
+    for attr in element.attributes().with_checks(false).flatten() {
+        let key = str::from_utf8(attr.key.as_ref())?;
+        if let Some(prefix) = key.strip_prefix("xmlns:") {
+            declared.push(prefix.to_string());
+        } else if let Some(prefix) = attr.key.prefix() {
+            used.push(str::from_utf8(prefix.as_ref())?.to_string());
+        }
+    }
+
+    for prefix in used {
+        let in_scope = declared.contains(&prefix) || scopes.iter().flatten().any(|p| *p == prefix);
+        if in_scope {
+            continue;
+        }
+        if let Some(uri) = namespaces.get(&prefix) {
+            element.push_attribute((format!("xmlns:{}", prefix).as_str(), uri.as_str()));
+            declared.push(prefix);
+        }
+    }
+
+    Ok((element, declared))
+}
@@ -6,14 +6,20 @@
 // it under the terms of the MIT License and/or Apache 2.0 License.
 
 use std::borrow::Cow;
-use std::io::BufRead;
+use std::io::{self, BufRead, Read};
 
-use quick_xml::events::attributes::Attribute;
+use quick_xml::escape::{resolve_xml_entity, unescape_with};
+use quick_xml::events::attributes::{Attribute, Attributes};
 use quick_xml::events::Event;
 use quick_xml::name::QName;
+use quick_xml::Error as XmlError;
 use quick_xml::Reader;
 
+use crate::category::Category;
+#[cfg(feature = "html-entities")]
+use crate::entities::resolve_html5_entity;
 use crate::error::Error;
+use crate::options::{EntitySet, ReadOptions};
 
 pub(crate) fn decode<'s, B: BufRead>(
     bytes: &'s [u8],
@@ -23,6 +29,34 @@ pub(crate) fn decode<'s, B: BufRead>(
     Ok(text)
 }
 
+/// Reads input encoded as UTF-16, which the XML reader cannot parse, into a string.
+///
+/// Returns `None` without consuming the input unless it starts with a UTF-16 byte order mark or
+/// an XML declaration encoded as UTF-16.
+pub(crate) fn read_utf16<R: BufRead>(reader: &mut R) -> Result<Option<String>, Error> {
+    let (encoding, bom_len) = match quick_xml::encoding::detect_encoding(reader.fill_buf()?) {
+        Some((encoding, bom_len)) if encoding.name().starts_with("UTF-16") => (encoding, bom_len),
+        _ => return Ok(None),
+    };
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let text = quick_xml::encoding::decode(&bytes[bom_len..], encoding)?;
+    Ok(Some(text.into_owned()))
+}
+
+/// Replaces the encoding named by the XML declaration at the start of `text` with UTF-8 and
+/// returns the encoding that was declared.
+pub(crate) fn declare_utf8(text: &mut String) -> Option<String> {
+    let decl_end = text.strip_prefix("<?xml")?.find("?>")? + "<?xml".len();
+    let name_end = text[..decl_end].find("encoding")? + "encoding".len();
+    let value_start = name_end + text[name_end..decl_end].find(['"', '\''])? + 1;
+    let quote = text[value_start - 1..].chars().next()?;
+    let value_end = value_start + text[value_start..decl_end].find(quote)?;
+    let declared = text[value_start..value_end].to_string();
+    text.replace_range(value_start..value_end, "UTF-8");
+    Some(declared)
+}
+
 pub(crate) fn attr_value<'s, B: BufRead>(
     attr: &'s Attribute<'s>,
     reader: &Reader<B>,
@@ -37,6 +71,14 @@ pub(crate) fn skip<B: BufRead>(end: QName<'_>, reader: &mut Reader<B>) -> Result
 }
 
 pub fn element_text<R: BufRead>(reader: &mut Reader<R>) -> Result<Option<String>, Error> {
+    element_text_with(reader, &ReadOptions::default())
+}
+
+/// Reads the text of an element like `element_text`, honoring the text options of `options`.
+pub(crate) fn element_text_with<R: BufRead>(
+    reader: &mut Reader<R>,
+    options: &ReadOptions,
+) -> Result<Option<String>, Error> {
     let mut content = String::new();
     let mut buf = Vec::new();
 
@@ -45,9 +87,16 @@ pub fn element_text<R: BufRead>(reader: &mut Reader<R>) -> Result<Option<String>
             Event::Start(element) => {
                 skip(element.name(), reader)?;
             }
+            Event::Text(element) if options.preserve_unknown_entities => {
+                let decoded = decode(&element, reader)?;
+                content.push_str(unescape_known_entities(&decoded, options.entity_set).as_ref());
+            }
             Event::Text(element) => {
-                let decoded = element.unescape()?;
-                content.push_str(decoded.as_ref());
+                let decoded = decode(&element, reader)?;
+                let unescaped =
+                    unescape_with(&decoded, |name| resolve_entity(name, options.entity_set))
+                        .map_err(XmlError::from)?;
+                content.push_str(unescaped.as_ref());
             }
             Event::CData(element) => {
                 content.push_str(decode(&element, reader)?.as_ref());
@@ -60,3 +109,393 @@ pub fn element_text<R: BufRead>(reader: &mut Reader<R>) -> Result<Option<String>
 
     Ok(Some(content.trim().to_owned()).filter(|c| !c.is_empty()))
 }
+
+/// Returns the replacement text of the named entity `name` in `entity_set`.
+fn resolve_entity(name: &str, entity_set: EntitySet) -> Option<&'static str> {
+    match entity_set {
+        #[cfg(feature = "html-entities")]
+        EntitySet::Html5 => resolve_html5_entity(name),
+        _ => resolve_xml_entity(name),
+    }
+}
+
+/// Unescapes character references and the entities of `entity_set`, leaving any other entity
+/// reference, and any `&` that does not start a reference, as written.
+fn unescape_known_entities(raw: &str, entity_set: EntitySet) -> Cow<'_, str> {
+    if !raw.contains('&') {
+        return Cow::Borrowed(raw);
+    }
+
+    let mut unescaped = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let len = match rest[1..].find(['&', ';']) {
+            Some(end) if rest.as_bytes()[end + 1] == b';' => end + 2,
+            _ => 1,
+        };
+        let reference = &rest[..len];
+        match unescape_with(reference, |name| resolve_entity(name, entity_set)) {
+            Ok(value) => unescaped.push_str(&value),
+            Err(_) => unescaped.push_str(reference),
+        }
+        rest = &rest[len..];
+    }
+    unescaped.push_str(rest);
+
+    Cow::Owned(unescaped)
+}
+
+/// Returns the value of the `xml:lang` attribute, if present.
+pub(crate) fn xml_lang<R: BufRead>(
+    reader: &Reader<R>,
+    mut atts: Attributes,
+) -> Result<Option<String>, Error> {
+    for attr in atts.with_checks(false).flatten() {
+        if attr.key.as_ref() == b"xml:lang" {
+            return Ok(Some(attr_value(&attr, reader)?.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+/// 64-bit FNV-1a hash. Unlike the std hashers its output is stable across releases.
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Convert HTML to plain text by removing tags, the contents of `script` and `style`
+/// elements and comments, decoding entities and collapsing whitespace.
+pub(crate) fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '<' => {
+                rest = skip_tag(rest);
+                text.push(' ');
+            }
+            '&' => {
+                let (decoded, len) = decode_entity(rest);
+                text.push_str(&decoded);
+                rest = &rest[len..];
+            }
+            c => {
+                text.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    // non-breaking spaces are kept
+    text.split(|c: char| c.is_whitespace() && c != '\u{A0}')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the input after the tag, comment or raw text element it starts with.
+fn skip_tag(input: &str) -> &str {
+    if let Some(comment) = input.strip_prefix("<!--") {
+        return comment.find("-->").map_or("", |end| &comment[end + 3..]);
+    }
+
+    let mut quote = None;
+    let mut end = input.len();
+    for (i, c) in input.char_indices().skip(1) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '>') => {
+                end = i + 1;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let tag = &input[..end];
+    let rest = &input[end..];
+    let name = tag[1..]
+        .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+        .next()
+        .unwrap_or_default();
+    for raw in ["script", "style"] {
+        if name.eq_ignore_ascii_case(raw) && !tag.ends_with("/>") {
+            let close = format!("</{}", raw);
+            return match rest.to_ascii_lowercase().find(&close) {
+                Some(start) => skip_tag(&rest[start..]),
+                None => "",
+            };
+        }
+    }
+    rest
+}
+
+/// Decodes the entity the input starts with, returning the text and the length consumed. An
+/// unknown entity is kept as an `&`.
+fn decode_entity(input: &str) -> (Cow<'static, str>, usize) {
+    // the longest HTML5 entity name has 31 characters
+    let entity = input[1..]
+        .char_indices()
+        .take(32)
+        .find(|(_, c)| *c == ';')
+        .map(|(end, _)| &input[1..end + 1]);
+    let decoded = entity.and_then(|entity| match entity.strip_prefix('#') {
+        Some(number) => {
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16),
+                None => number.parse(),
+            };
+            code.ok()
+                .and_then(char::from_u32)
+                .map(|c| Cow::Owned(c.to_string()))
+        }
+        None => named_entity(entity).map(Cow::Borrowed),
+    });
+    match (entity, decoded) {
+        (Some(entity), Some(text)) => (text, entity.len() + 2),
+        _ => (Cow::Borrowed("&"), 1),
+    }
+}
+
+/// Resolves a named entity using the HTML5 entities.
+#[cfg(feature = "html-entities")]
+fn named_entity(name: &str) -> Option<&'static str> {
+    crate::entities::resolve_html5_entity(name)
+}
+
+/// Resolves the named entities of XML and a non-breaking space.
+#[cfg(not(feature = "html-entities"))]
+fn named_entity(name: &str) -> Option<&'static str> {
+    match name {
+        "amp" => Some("&"),
+        "lt" => Some("<"),
+        "gt" => Some(">"),
+        "quot" => Some("\""),
+        "apos" => Some("'"),
+        "nbsp" => Some("\u{A0}"),
+        _ => None,
+    }
+}
+
+/// Truncate text to at most `max_chars` characters, including the ellipsis appended when it
+/// is truncated, cutting at a word boundary when there is one.
+pub(crate) fn truncate_words(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    let end = text
+        .char_indices()
+        .nth(max_chars - 1)
+        .map_or(text.len(), |(i, _)| i);
+    let mut truncated = &text[..end];
+    // cut at the last word boundary unless the cut is already at one
+    if !text[end..].starts_with(char::is_whitespace) {
+        if let Some(boundary) = truncated.rfind(char::is_whitespace) {
+            truncated = &truncated[..boundary];
+        }
+    }
+    format!("{}\u{2026}", truncated.trim_end())
+}
+
+/// A reader that keeps a copy of the bytes consumed from the inner reader when recording.
+pub(crate) struct Recorder<R> {
+    inner: R,
+    recorded: Option<Vec<u8>>,
+}
+
+impl<R> Recorder<R> {
+    pub(crate) fn new(inner: R, record: bool) -> Self {
+        Recorder {
+            inner,
+            recorded: if record { Some(Vec::new()) } else { None },
+        }
+    }
+
+    /// Returns the bytes consumed so far, or an empty slice when not recording.
+    pub(crate) fn recorded(&self) -> &[u8] {
+        self.recorded.as_deref().unwrap_or_default()
+    }
+}
+
+impl<R: Read> Read for Recorder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        if let Some(recorded) = self.recorded.as_mut() {
+            recorded.extend_from_slice(&buf[..len]);
+        }
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for Recorder<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if let Some(recorded) = self.recorded.as_mut() {
+            // the buffer returned by the last `fill_buf` is returned again without reading
+            if let Ok(buf) = self.inner.fill_buf() {
+                recorded.extend_from_slice(&buf[..amt.min(buf.len())]);
+            }
+        }
+        self.inner.consume(amt);
+    }
+}
+
+/// Returns true if `text` looks like an absolute URL: a scheme followed by `://` and no
+/// whitespace.
+pub(crate) fn looks_like_url(text: &str) -> bool {
+    let text = text.trim();
+    match text.split_once("://") {
+        Some((scheme, rest)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                && !rest.is_empty()
+                && !text.contains(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+/// Removes whitespace from the start and end of `text` without reallocating.
+pub(crate) fn trim_in_place(text: &mut String) {
+    let end = text.trim_end().len();
+    text.truncate(end);
+    let start = text.len() - text.trim_start().len();
+    text.drain(..start);
+}
+
+/// Trims `date` and, with the `validation` feature, rewrites it as RFC 2822 if it can be parsed.
+pub(crate) fn normalize_date(date: &mut String) {
+    trim_in_place(date);
+    #[cfg(feature = "validation")]
+    if let Some(parsed) = crate::date::parse_date(date) {
+        *date = parsed.to_rfc2822();
+    }
+}
+
+/// Trims the names and domains of `categories` and sorts them by domain, then name.
+pub(crate) fn normalize_categories(categories: &mut [Category]) {
+    for category in categories.iter_mut() {
+        trim_in_place(&mut category.name);
+        if let Some(domain) = category.domain.as_mut() {
+            trim_in_place(domain);
+        }
+    }
+    categories.sort_by(|a, b| (&a.domain, &a.name).cmp(&(&b.domain, &b.name)));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fnv1a_64_reference_values() {
+        assert_eq!(fnv1a_64(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a_64(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn html_to_text_strips_markup() {
+        assert_eq!(
+            html_to_text("<p class=\"a>b\">Fish &amp; chips</p><br/>&#233;t&#xE9; &bogus; &"),
+            "Fish & chips \u{e9}t\u{e9} &bogus; &"
+        );
+        assert_eq!(
+            html_to_text("a<script>if (x < y) {}</script>b<!-- <p>c</p> -->d<STYLE>p {}</style>e"),
+            "a b d e"
+        );
+    }
+
+    #[test]
+    fn html_to_text_decodes_entities() {
+        assert_eq!(
+            html_to_text("a&nbsp;&nbsp;b &lt;c&gt;"),
+            "a\u{A0}\u{A0}b <c>"
+        );
+        #[cfg(feature = "html-entities")]
+        assert_eq!(
+            html_to_text("&hellip;&eacute;&CounterClockwiseContourIntegral;"),
+            "\u{2026}\u{e9}\u{2233}"
+        );
+        #[cfg(not(feature = "html-entities"))]
+        assert_eq!(html_to_text("&hellip;"), "&hellip;");
+    }
+
+    #[test]
+    fn trim_in_place_trims_both_ends() {
+        let mut text = " \n a b\t".to_string();
+        trim_in_place(&mut text);
+        assert_eq!(text, "a b");
+
+        let mut text = "  ".to_string();
+        trim_in_place(&mut text);
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn declare_utf8_replaces_declared_encoding() {
+        let mut text = "<?xml version='1.0' encoding='UTF-16'?><rss/>".to_string();
+        assert_eq!(declare_utf8(&mut text).as_deref(), Some("UTF-16"));
+        assert_eq!(text, "<?xml version='1.0' encoding='UTF-8'?><rss/>");
+
+        let mut text = r#"<?xml version="1.0"?><rss encoding="x"/>"#.to_string();
+        assert_eq!(declare_utf8(&mut text), None);
+        assert_eq!(text, r#"<?xml version="1.0"?><rss encoding="x"/>"#);
+    }
+
+    #[test]
+    fn element_text_joins_text_and_cdata() {
+        let mut reader = Reader::from_str(concat!(
+            "<d> a &amp; <![CDATA[<b>]]>c<![CDATA[]]]]><![CDATA[>]]><![CDATA[ ]]>d ",
+            "<!-- comment -->e</d>"
+        ));
+        reader.read_event().unwrap();
+        assert_eq!(
+            element_text(&mut reader).unwrap().as_deref(),
+            Some("a & <b>c]]> d e")
+        );
+    }
+
+    #[test]
+    fn unescape_known_entities_keeps_unknown() {
+        let raw = "a &mdash; b &amp; &#233;&#xE9; & c;&lt;&bogus";
+        assert_eq!(unescape_known_entities("plain", EntitySet::Xml), "plain");
+        assert_eq!(
+            unescape_known_entities(raw, EntitySet::Xml),
+            "a &mdash; b & \u{e9}\u{e9} & c;<&bogus"
+        );
+        #[cfg(feature = "html-entities")]
+        assert_eq!(
+            unescape_known_entities(raw, EntitySet::Html5),
+            "a \u{2014} b & \u{e9}\u{e9} & c;<&bogus"
+        );
+    }
+
+    #[test]
+    fn truncate_words_at_boundary() {
+        assert_eq!(truncate_words("one two three", 13), "one two three");
+        assert_eq!(truncate_words("one two three", 10), "one two\u{2026}");
+        assert_eq!(truncate_words("one two three", 8), "one two\u{2026}");
+        assert_eq!(truncate_words("onetwothree", 5), "onet\u{2026}");
+        assert_eq!(truncate_words("one", 0), "");
+    }
+}
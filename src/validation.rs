@@ -10,14 +10,14 @@ use std::error::Error as StdError;
 use std::fmt;
 use std::num::ParseIntError;
 
-use chrono::DateTime;
 use chrono::ParseError as DateParseError;
 use mime::FromStrError as MimeParseError;
 use mime::Mime;
 use url::ParseError as UrlParseError;
 use url::Url;
 
-use crate::{Category, Channel, Cloud, Enclosure, Image, Item, Source, TextInput};
+pub use crate::date::parse_rfc822_date;
+use crate::{Category, Channel, Cloud, CloudProtocol, Enclosure, Image, Item, Source, TextInput};
 
 #[derive(Debug)]
 /// Errors that occur during validation.
@@ -81,12 +81,6 @@ impl From<MimeParseError> for ValidationError {
     }
 }
 
-/// A trait to support data validation.
-pub trait Validate {
-    /// Validate the data against the RSS specification.
-    fn validate(&self) -> Result<(), ValidationError>;
-}
-
 macro_rules! validate {
     ($e: expr, $msg: expr) => {{
         if !($e) {
@@ -95,36 +89,188 @@ macro_rules! validate {
     }};
 }
 
+/// Options that control validation.
+///
+/// # Examples
+///
+/// ```
+/// use rss::validation::{Validate, ValidationOptions};
+/// use rss::Enclosure;
+///
+/// let mut enclosure = Enclosure::default();
+/// enclosure.set_url("javascript:alert(1)");
+/// enclosure.set_length("0");
+/// enclosure.set_mime_type("audio/mpeg");
+/// assert!(enclosure.validate().is_ok());
+///
+/// let options = ValidationOptions {
+///     allowed_url_schemes: ["http", "https"].iter().map(|s| s.to_string()).collect(),
+/// };
+/// assert!(enclosure.validate_with(&options).is_err());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationOptions {
+    /// The schemes accepted in URLs, compared case-insensitively. An empty set, the default,
+    /// accepts every scheme.
+    pub allowed_url_schemes: HashSet<String>,
+}
+
+impl ValidationOptions {
+    fn parse_url(&self, input: &str) -> Result<Url, ValidationError> {
+        let url = Url::parse(input)?;
+        validate!(
+            self.allowed_url_schemes.is_empty()
+                || self
+                    .allowed_url_schemes
+                    .iter()
+                    .any(|scheme| scheme.eq_ignore_ascii_case(url.scheme())),
+            format!("URL scheme is not allowed: {}", url.scheme())
+        );
+        Ok(url)
+    }
+}
+
+/// A trait to support data validation.
+pub trait Validate {
+    /// Validate the data against the RSS specification.
+    fn validate(&self) -> Result<(), ValidationError>;
+
+    /// Validate the data against the RSS specification using the given options.
+    ///
+    /// The default implementation ignores the options and calls `validate`.
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        let _ = options;
+        self.validate()
+    }
+}
+
 impl Validate for Channel {
     fn validate(&self) -> Result<(), ValidationError> {
-        Url::parse(self.link())?;
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        self.validate_fields(options)?;
+
+        for item in self.items() {
+            item.validate_with(options)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Channel {
+    /// Validate the channel and its items, validating the items in parallel.
+    ///
+    /// Unlike `validate`, every error is returned: the error of the channel's own fields, if
+    /// any, followed by the errors of the items in order.
+    ///
+    /// **Note**: This requires enabling the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::{Channel, Item};
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_title("Title");
+    /// channel.set_link("http://example.com");
+    /// channel.set_description("Description");
+    /// channel.set_items(vec![Item::default(), Item::default()]);
+    /// assert_eq!(channel.validate_par().unwrap_err().len(), 2);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn validate_par(&self) -> Result<(), Vec<ValidationError>> {
+        self.validate_par_with(&ValidationOptions::default())
+    }
+
+    /// Validate the channel and its items using the given options, validating the items in
+    /// parallel.
+    ///
+    /// The options are applied as by `validate_with`; every error is returned as by
+    /// `validate_par`.
+    ///
+    /// **Note**: This requires enabling the `rayon` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rss::validation::ValidationOptions;
+    /// use rss::{Channel, Item};
+    ///
+    /// let mut item = Item::default();
+    /// item.set_title("Item".to_string());
+    /// item.set_link("ftp://example.com/item".to_string());
+    ///
+    /// let mut channel = Channel::default();
+    /// channel.set_title("Title");
+    /// channel.set_link("http://example.com");
+    /// channel.set_description("Description");
+    /// channel.set_items(vec![item]);
+    /// assert!(channel.validate_par().is_ok());
+    ///
+    /// let options = ValidationOptions {
+    ///     allowed_url_schemes: ["http", "https"].iter().map(|s| s.to_string()).collect(),
+    /// };
+    /// assert_eq!(channel.validate_par_with(&options).unwrap_err().len(), 1);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn validate_par_with(
+        &self,
+        options: &ValidationOptions,
+    ) -> Result<(), Vec<ValidationError>> {
+        use rayon::prelude::*;
+
+        let mut errors = self
+            .validate_fields(options)
+            .err()
+            .into_iter()
+            .collect::<Vec<_>>();
+        errors.par_extend(
+            self.items()
+                .par_iter()
+                .filter_map(|item| item.validate_with(options).err()),
+        );
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate the fields of the channel other than its items.
+    fn validate_fields(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        validate!(!self.title().trim().is_empty(), "Channel title is empty");
+        options.parse_url(self.link())?;
+        validate!(
+            !self.description().trim().is_empty(),
+            "Channel description is empty"
+        );
 
         for category in self.categories() {
-            category.validate()?;
+            category.validate_with(options)?;
         }
 
         if let Some(cloud) = self.cloud() {
-            cloud.validate()?;
+            cloud.validate_with(options)?;
         }
 
         if let Some(docs) = self.docs() {
-            Url::parse(docs)?;
+            options.parse_url(docs)?;
         }
 
         if let Some(image) = self.image() {
-            image.validate()?;
-        }
-
-        for item in self.items() {
-            item.validate()?;
+            image.validate_with(options)?;
         }
 
         if let Some(last_build_date) = self.last_build_date() {
-            DateTime::parse_from_rfc2822(last_build_date)?;
+            parse_rfc822_date(last_build_date)?;
         }
 
         if let Some(pub_date) = self.pub_date() {
-            DateTime::parse_from_rfc2822(pub_date)?;
+            parse_rfc822_date(pub_date)?;
         }
 
         for hour in self.skip_hours() {
@@ -155,7 +301,18 @@ impl Validate for Channel {
         }
 
         if let Some(text_input) = self.text_input() {
-            text_input.validate()?;
+            text_input.validate_with(options)?;
+        }
+
+        if let Some(new_feed_url) = self.moved_to() {
+            options.parse_url(new_feed_url)?;
+        }
+
+        #[cfg(feature = "atom")]
+        if let Some(atom_ext) = self.atom_ext() {
+            for link in atom_ext.links() {
+                link.validate_with(options)?;
+            }
         }
 
         if let Some(ttl) = self.ttl() {
@@ -169,8 +326,12 @@ impl Validate for Channel {
 
 impl Validate for Category {
     fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
         if let Some(domain) = self.domain() {
-            Url::parse(domain)?;
+            options.parse_url(domain)?;
         }
         Ok(())
     }
@@ -178,11 +339,22 @@ impl Validate for Category {
 
 impl Validate for Cloud {
     fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
         let port = self.port().parse::<i64>()?;
-        validate!(port > 0, "Cloud port must be greater than 0");
-        Url::parse(self.domain())?;
         validate!(
-            vec!["xml-rpc", "soap", "http-post"].contains(&self.protocol()),
+            (1..=65535).contains(&port),
+            "Cloud port is not between 1 and 65535"
+        );
+        options.parse_url(self.domain())?;
+        validate!(
+            self.path().starts_with('/'),
+            format!("Cloud path does not begin with '/': {}", self.path())
+        );
+        validate!(
+            !matches!(self.protocol_parsed(), CloudProtocol::Other(_)),
             format!("Unknown cloud protocol: {}", self.protocol())
         );
         Ok(())
@@ -191,25 +363,38 @@ impl Validate for Cloud {
 
 impl Validate for Enclosure {
     fn validate(&self) -> Result<(), ValidationError> {
-        Url::parse(self.url())?;
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        options.parse_url(self.url())?;
         self.mime_type().parse::<Mime>()?;
+        // A length of 0 is used by podcast feeds for live or unknown-size media.
         let length = self.length().parse::<i64>()?;
-        validate!(length > 0, "Enclosure length is not greater than 0");
+        validate!(length >= 0, "Enclosure length is negative");
         Ok(())
     }
 }
 
 impl Validate for TextInput {
     fn validate(&self) -> Result<(), ValidationError> {
-        Url::parse(self.link())?;
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        options.parse_url(self.link())?;
         Ok(())
     }
 }
 
 impl Validate for Image {
     fn validate(&self) -> Result<(), ValidationError> {
-        Url::parse(self.link())?;
-        Url::parse(self.url())?;
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        options.parse_url(self.link())?;
+        options.parse_url(self.url())?;
 
         if let Some(width) = self.width() {
             let width = width.parse::<i64>()?;
@@ -231,26 +416,45 @@ impl Validate for Image {
     }
 }
 
+/// Items are also validated to contain a title or a description, as required by RSS 2.0. The
+/// HTML content (`content:encoded`) and a Dublin Core description are accepted in place of the
+/// description.
 impl Validate for Item {
     fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        validate!(
+            self.has_title_or_description(),
+            "item has neither title nor description"
+        );
+
         if let Some(link) = self.link() {
-            Url::parse(link)?;
+            options.parse_url(link)?;
         }
 
         if let Some(comments) = self.comments() {
-            Url::parse(comments)?;
+            options.parse_url(comments)?;
         }
 
         if let Some(enclosure) = self.enclosure() {
-            enclosure.validate()?;
+            enclosure.validate_with(options)?;
         }
 
         if let Some(pub_date) = self.pub_date() {
-            DateTime::parse_from_rfc2822(pub_date)?;
+            parse_rfc822_date(pub_date)?;
         }
 
         if let Some(source) = self.source() {
-            source.validate()?;
+            source.validate_with(options)?;
+        }
+
+        #[cfg(feature = "atom")]
+        if let Some(atom_ext) = self.atom_ext() {
+            for link in atom_ext.links() {
+                link.validate_with(options)?;
+            }
         }
 
         Ok(())
@@ -259,7 +463,26 @@ impl Validate for Item {
 
 impl Validate for Source {
     fn validate(&self) -> Result<(), ValidationError> {
-        Url::parse(self.url())?;
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        options.parse_url(self.url())?;
+        Ok(())
+    }
+}
+
+/// The `rel` of a link must not be empty. It is not checked against the IANA registry, which keeps
+/// growing, or for being an absolute URI.
+#[cfg(feature = "atom")]
+impl Validate for crate::extension::atom::Link {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.validate_with(&ValidationOptions::default())
+    }
+
+    fn validate_with(&self, options: &ValidationOptions) -> Result<(), ValidationError> {
+        options.parse_url(self.href())?;
+        validate!(!self.rel().trim().is_empty(), "Link relation is empty");
         Ok(())
     }
 }
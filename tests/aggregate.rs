@@ -0,0 +1,68 @@
+#![cfg(feature = "aggregate")]
+
+extern crate rss;
+
+use rss::aggregate::AggregateBuilder;
+use rss::{Channel, Guid, Item};
+
+fn item(guid: &str, pub_date: Option<&str>) -> Item {
+    let mut item = Item::default();
+    item.set_title(guid.to_string());
+    item.set_guid(Guid {
+        value: guid.to_string(),
+        permalink: false,
+        ..Default::default()
+    });
+    item.set_pub_date(pub_date.map(str::to_string));
+    item
+}
+
+fn titles(channel: &Channel) -> Vec<&str> {
+    channel
+        .items()
+        .iter()
+        .map(|item| item.title().unwrap())
+        .collect()
+}
+
+#[test]
+fn aggregate_channels() {
+    let mut first = Channel::from_iter(vec![
+        item("a1", Some("Sun, 01 Jan 2017 12:00:00 GMT")),
+        item("a2", Some("Tue, 03 Jan 2017 12:00:00 GMT")),
+        item("a3", None),
+    ]);
+    first.namespaces.insert(
+        "dc".to_string(),
+        "http://purl.org/dc/elements/1.1/".to_string(),
+    );
+    let second = Channel::from_iter(vec![
+        item("b1", Some("2017-01-02T12:00:00Z")),
+        item("b2", Some("Wed, 04 Jan 2017 12:00:00 +0100")),
+        item("a1", Some("Thu, 05 Jan 2017 12:00:00 GMT")),
+    ]);
+
+    let mut builder = AggregateBuilder::default();
+    builder
+        .title("Planet")
+        .link("http://example.com/")
+        .description("All the feeds")
+        .channels(vec![first, second]);
+
+    let channel = builder.build();
+    assert_eq!(channel.title(), "Planet");
+    assert_eq!(channel.link(), "http://example.com/");
+    assert_eq!(channel.description(), "All the feeds");
+    assert_eq!(
+        channel.namespaces().get("dc").map(String::as_str),
+        Some("http://purl.org/dc/elements/1.1/")
+    );
+    assert_eq!(titles(&channel), vec!["a1", "b2", "a2", "b1", "a3"]);
+    assert_eq!(
+        channel.items()[0].pub_date(),
+        Some("Thu, 05 Jan 2017 12:00:00 GMT")
+    );
+
+    let channel = builder.max_items(3).build();
+    assert_eq!(titles(&channel), vec!["a1", "b2", "a2"]);
+}
@@ -1,11 +1,17 @@
 extern crate rss;
 
 use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use rss::extension::dublincore::DublinCoreExtension;
+use rss::extension::media::Medium;
 use rss::extension::syndication;
-use rss::extension::Extension;
-use rss::Channel;
+use rss::extension::{parse_fragment, Extension};
+use rss::{
+    Channel, Cloud, CloudProtocol, ElementHandler, Enclosure, EntitySet, Error, FeedFormat, Item,
+    Lint, ReadOptions, XmlDeclaration,
+};
 
 fn get_extension_values<'a>(
     map: &'a BTreeMap<String, Vec<Extension>>,
@@ -34,7 +40,7 @@ fn read_rss090() {
 
     assert_eq!(channel.items().len(), 5);
 
-    let item = channel.items().get(0).unwrap();
+    let item = channel.items().first().unwrap();
     assert_eq!(item.title(), Some("New Status Updates"));
     assert_eq!(item.link(), Some("http://www.mozilla.org/status/"));
 }
@@ -71,7 +77,7 @@ fn read_rss091() {
 
     assert_eq!(channel.items().len(), 6);
 
-    let item = channel.items().get(0).unwrap();
+    let item = channel.items().first().unwrap();
     assert_eq!(item.title(), Some("Giving the world a pluggable Gnutella"));
     assert_eq!(item.link(), Some("http://writetheweb.com/read.php?item=24"));
     assert_eq!(
@@ -123,7 +129,7 @@ fn read_rss092() {
 
     assert_eq!(channel.items().len(), 22);
 
-    let item = channel.items().get(0).unwrap();
+    let item = channel.items().first().unwrap();
     assert_eq!(
         item.description(),
         Some(
@@ -175,7 +181,7 @@ fn read_rss1() {
 
     assert_eq!(channel.items().len(), 2);
 
-    let item = channel.items().get(0).unwrap();
+    let item = channel.items().first().unwrap();
     assert_eq!(item.title(), Some("Processing Inclusions with XSLT"));
     assert_eq!(
         item.link(),
@@ -213,9 +219,9 @@ fn read_channel() {
         Some("http://blogs.law.harvard.edu/tech/rss")
     );
     assert_eq!(channel.ttl(), Some("60"));
-    assert_eq!(channel.skip_hours().get(0).unwrap().as_str(), "6");
+    assert_eq!(channel.skip_hours().first().unwrap().as_str(), "6");
     assert_eq!(channel.skip_hours().get(1).unwrap().as_str(), "8");
-    assert_eq!(channel.skip_days().get(0).unwrap().as_str(), "Tuesday");
+    assert_eq!(channel.skip_days().first().unwrap().as_str(), "Tuesday");
     assert_eq!(channel.skip_days().get(1).unwrap().as_str(), "Thursday");
 }
 
@@ -224,33 +230,71 @@ fn read_item() {
     let input = include_str!("data/item.xml");
     let channel = input.parse::<Channel>().expect("failed to parse xml");
 
-    assert_eq!(channel.items().get(0).unwrap().title(), Some("Title"));
+    assert_eq!(channel.items().first().unwrap().title(), Some("Title"));
     assert_eq!(
-        channel.items().get(0).unwrap().link(),
+        channel.items().first().unwrap().link(),
         Some("http://example.com/")
     );
     assert_eq!(
-        channel.items().get(0).unwrap().description(),
+        channel.items().first().unwrap().description(),
         Some("Description")
     );
     assert_eq!(
-        channel.items().get(0).unwrap().author(),
+        channel.items().first().unwrap().author(),
         Some("author@example.com")
     );
-    assert_eq!(channel.items().get(0).unwrap().comments(), Some("Comments"));
     assert_eq!(
-        channel.items().get(0).unwrap().pub_date(),
+        channel.items().first().unwrap().comments(),
+        Some("Comments")
+    );
+    assert_eq!(
+        channel.items().first().unwrap().pub_date(),
         Some("Sat, 27 Aug 2016 00:00:00 GMT")
     );
 }
 
+#[cfg(feature = "validation")]
+#[test]
+fn read_item_published() {
+    let input = r#"
+    <rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <item>
+                <pubDate>not a date</pubDate>
+                <dc:date>2017-01-01T12:00:00+02:00</dc:date>
+            </item>
+            <item>
+                <atom:published>2017-01-02T12:00:00Z</atom:published>
+            </item>
+            <item>
+                <title>Undated</title>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let published = channel
+        .items()
+        .iter()
+        .map(|item| item.published().map(|date| date.to_rfc3339()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        published,
+        vec![
+            Some("2017-01-01T12:00:00+02:00".to_string()),
+            Some("2017-01-02T12:00:00+00:00".to_string()),
+            None,
+        ]
+    );
+}
+
 #[test]
 fn read_content() {
     let input = include_str!("data/content.xml");
     let channel = input.parse::<Channel>().expect("failed to parse xml");
 
     assert_eq!(
-        channel.items().get(0).unwrap().content(),
+        channel.items().first().unwrap().content(),
         Some("An example <a href=\"http://example.com/\">link</a>.")
     );
 }
@@ -263,7 +307,7 @@ fn read_source() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .source()
             .as_ref()
@@ -273,7 +317,7 @@ fn read_source() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .source()
             .as_ref()
@@ -290,7 +334,7 @@ fn read_guid() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .guid()
             .as_ref()
@@ -300,7 +344,7 @@ fn read_guid() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .guid()
             .as_ref()
@@ -330,6 +374,29 @@ fn read_guid() {
     );
 }
 
+#[test]
+fn read_guid_permalink_values() {
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <item><guid isPermaLink="False">1</guid></item>
+            <item><guid isPermaLink="0">2</guid></item>
+            <item><guid isPermaLink="TRUE">3</guid></item>
+            <item><guid isPermaLink="1">4</guid></item>
+            <item><guid isPermaLink="maybe">5</guid></item>
+            <item><guid>6</guid></item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let permalinks = channel
+        .items()
+        .iter()
+        .map(|item| item.guid().unwrap().is_permalink())
+        .collect::<Vec<_>>();
+    assert_eq!(permalinks, vec![false, false, true, true, true, true]);
+}
+
 #[test]
 fn read_enclosure() {
     let input = include_str!("data/enclosure.xml");
@@ -338,7 +405,7 @@ fn read_enclosure() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .enclosure()
             .as_ref()
@@ -348,7 +415,7 @@ fn read_enclosure() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .enclosure()
             .as_ref()
@@ -358,7 +425,7 @@ fn read_enclosure() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .enclosure()
             .as_ref()
@@ -367,13 +434,53 @@ fn read_enclosure() {
     );
 }
 
+#[test]
+fn read_enclosure_length_bytes() {
+    let input = r#"<rss version="2.0"><channel>
+        <item><enclosure url="http://example.com/a.mp3" length="4,992,349" type="audio/mpeg"/></item>
+        <item><enclosure url="http://example.com/b.mp3" length="4992349.0" type="audio/mpeg"/></item>
+        <item><enclosure url="http://example.com/c.mp3" length="unknown" type="audio/mpeg"/></item>
+    </channel></rss>"#;
+    let channel = input.parse::<Channel>().unwrap();
+    let enclosures = channel
+        .items()
+        .iter()
+        .map(|item| item.enclosure().unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(enclosures[0].length(), "4,992,349");
+    assert_eq!(enclosures[0].length_bytes(), Ok(4_992_349));
+    assert_eq!(enclosures[1].length(), "4992349.0");
+    assert_eq!(enclosures[1].length_bytes(), Ok(4_992_349));
+    assert!(enclosures[2].length_bytes().is_err());
+}
+
+#[test]
+fn read_enclosure_length_bytes_separators() {
+    let mut enclosure = Enclosure::default();
+    for (length, expected) in [
+        ("1,000", Some(1_000)),
+        ("12,345,678", Some(12_345_678)),
+        ("999", Some(999)),
+        ("1,2,3", None),
+        ("1000,000", None),
+        ("1,00", None),
+        (",100", None),
+        ("100,", None),
+        ("1,,000", None),
+    ] {
+        enclosure.set_length(length);
+        assert_eq!(enclosure.length_bytes().ok(), expected, "{}", length);
+    }
+}
+
 #[test]
 fn read_category() {
     let input = include_str!("data/category.xml");
     let channel = input.parse::<Channel>().expect("failed to parse xml");
 
-    assert_eq!(channel.categories().get(0).unwrap().domain(), None);
-    assert_eq!(channel.categories().get(0).unwrap().name(), "Category 1");
+    assert_eq!(channel.categories().first().unwrap().domain(), None);
+    assert_eq!(channel.categories().first().unwrap().name(), "Category 1");
 
     assert_eq!(
         channel.categories().get(1).unwrap().domain(),
@@ -384,10 +491,10 @@ fn read_category() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .categories()
-            .get(0)
+            .first()
             .unwrap()
             .domain(),
         None
@@ -395,10 +502,10 @@ fn read_category() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .categories()
-            .get(0)
+            .first()
             .unwrap()
             .name(),
         "Category 1"
@@ -407,7 +514,7 @@ fn read_category() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .categories()
             .get(1)
@@ -418,7 +525,7 @@ fn read_category() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .categories()
             .get(1)
@@ -462,6 +569,31 @@ fn read_cloud() {
     assert_eq!(cloud.protocol(), "xml-rpc");
 }
 
+#[test]
+fn read_cloud_protocol_parsed() {
+    let input = include_str!("data/cloud.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    let cloud = channel.cloud().expect("cloud missing");
+
+    assert_eq!(cloud.protocol_parsed(), CloudProtocol::XmlRpc);
+}
+
+#[test]
+fn read_cloud_protocol_round_trip() {
+    let mut cloud = Cloud::default();
+    for protocol in [
+        CloudProtocol::XmlRpc,
+        CloudProtocol::Soap,
+        CloudProtocol::HttpPost,
+        CloudProtocol::Other("xmlrpc".to_string()),
+    ] {
+        cloud.set_protocol_parsed(protocol.clone());
+        assert_eq!(cloud.protocol(), protocol.as_str());
+        assert_eq!(cloud.protocol_parsed(), protocol);
+        assert_eq!(CloudProtocol::from(protocol.to_string().as_str()), protocol);
+    }
+}
+
 #[test]
 fn read_textinput() {
     let input = include_str!("data/textinput.xml");
@@ -493,7 +625,7 @@ fn read_extension() {
         get_extension_values(
             channel
                 .items()
-                .get(0)
+                .first()
                 .unwrap()
                 .extensions()
                 .get("ext")
@@ -506,7 +638,7 @@ fn read_extension() {
         get_extension_values(
             channel
                 .items()
-                .get(0)
+                .first()
                 .unwrap()
                 .extensions()
                 .get("ext")
@@ -518,7 +650,7 @@ fn read_extension() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .extensions()
             .get("ext")
@@ -594,7 +726,7 @@ fn read_itunes() {
             .itunes_ext()
             .unwrap()
             .categories()
-            .get(0)
+            .first()
             .unwrap()
             .text(),
         "Category 1"
@@ -604,7 +736,7 @@ fn read_itunes() {
             .itunes_ext()
             .unwrap()
             .categories()
-            .get(0)
+            .first()
             .unwrap()
             .subcategory()
             .as_ref()
@@ -672,7 +804,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -682,7 +814,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -692,7 +824,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -702,7 +834,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -712,7 +844,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -722,7 +854,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -732,7 +864,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -742,7 +874,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -752,7 +884,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -762,7 +894,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -773,7 +905,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -784,7 +916,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -794,7 +926,7 @@ fn read_itunes() {
     assert_eq!(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .itunes_ext()
             .unwrap()
@@ -803,6 +935,14 @@ fn read_itunes() {
     );
 }
 
+#[test]
+fn read_itunes_moved_to() {
+    let input = include_str!("data/itunes.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+
+    assert_eq!(channel.moved_to(), Some("http://example.com/feed/"));
+}
+
 #[test]
 fn read_dublincore() {
     run_dublincore_test(include_str!("data/dublincore.xml"));
@@ -911,7 +1051,7 @@ fn run_dublincore_test(input: &str) {
     test_ext(
         channel
             .items()
-            .get(0)
+            .first()
             .unwrap()
             .dublin_core_ext()
             .as_ref()
@@ -982,6 +1122,11 @@ fn read_local_namespace() {
 
     assert!(channel.dublin_core_ext().is_some());
     assert_eq!(channel.dublin_core_ext().unwrap().creators, vec!["Creator"]);
+    assert_eq!(
+        channel.dublin_core_ext().unwrap().creator(),
+        Some("Creator")
+    );
+    assert_eq!(channel.dublin_core_ext().unwrap().date(), None);
 }
 
 #[test]
@@ -999,3 +1144,1768 @@ fn read_local_namespace_hijack() {
     assert!(channel.dublin_core_ext().is_some());
     assert_eq!(channel.dublin_core_ext().unwrap().creators, vec!["Creator"]);
 }
+
+#[test]
+fn read_namespace_rebound_in_item() {
+    let input = r#"
+    <rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd" xmlns:x="http://example.com/x">
+        <channel>
+            <itunes:author>Channel Author</itunes:author>
+            <item xmlns:itunes="http://example.com/not-itunes" xmlns:x="http://purl.org/dc/elements/1.1/">
+                <itunes:author>Item Author</itunes:author>
+                <x:creator>Item Creator</x:creator>
+            </item>
+            <item>
+                <itunes:author>Second Author</itunes:author>
+                <x:creator>Not Dublin Core</x:creator>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+
+    assert_eq!(
+        channel.namespaces().get("itunes").map(String::as_str),
+        Some("http://www.itunes.com/dtds/podcast-1.0.dtd")
+    );
+    assert_eq!(
+        channel.namespaces().get("x").map(String::as_str),
+        Some("http://example.com/x")
+    );
+    assert_eq!(
+        channel.itunes_ext().unwrap().author(),
+        Some("Channel Author")
+    );
+
+    let item = &channel.items()[0];
+    assert!(item.itunes_ext().is_none());
+    assert_eq!(
+        item.extensions()["itunes"]["author"][0].value(),
+        Some("Item Author")
+    );
+    assert_eq!(
+        item.dublin_core_ext().unwrap().creators(),
+        ["Item Creator".to_string()]
+    );
+
+    let item = &channel.items()[1];
+    assert_eq!(item.itunes_ext().unwrap().author(), Some("Second Author"));
+    assert!(item.dublin_core_ext().is_none());
+    assert_eq!(
+        item.extensions()["x"]["creator"][0].value(),
+        Some("Not Dublin Core")
+    );
+}
+
+#[test]
+fn read_multiple_content() {
+    let input = r#"
+    <rss version="2.0" xmlns:content="http://purl.org/rss/1.0/modules/content/">
+        <channel>
+            <item>
+                <content:encoded><![CDATA[<p>Full content</p>]]></content:encoded>
+                <content:encoded><![CDATA[<p>Summary</p>]]></content:encoded>
+            </item>
+            <item>
+                <content:encoded></content:encoded>
+                <content:encoded>Second</content:encoded>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+
+    assert_eq!(channel.items[0].content(), Some("<p>Full content</p>"));
+    assert_eq!(channel.items[1].content(), Some("Second"));
+}
+
+#[test]
+fn read_prefixed_core_elements() {
+    let input = r#"
+    <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns:rss="http://purl.org/rss/1.0/">
+        <rss:channel>
+            <rss:title>Title</rss:title>
+            <rss:link>http://example.com/</rss:link>
+            <rss:description>Description</rss:description>
+        </rss:channel>
+        <rss:item>
+            <rss:title>Item Title</rss:title>
+            <rss:link>http://example.com/item</rss:link>
+        </rss:item>
+    </rdf:RDF>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+
+    assert_eq!(channel.title(), "Title");
+    assert_eq!(channel.link(), "http://example.com/");
+    assert_eq!(channel.description(), "Description");
+    assert!(channel.extensions().is_empty());
+    assert_eq!(channel.items().len(), 1);
+    assert_eq!(channel.items[0].title(), Some("Item Title"));
+    assert_eq!(channel.items[0].link(), Some("http://example.com/item"));
+}
+
+#[test]
+fn read_media_content() {
+    let input = r#"
+    <rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+        <channel>
+            <item>
+                <media:content url="http://example.com/video.mp4" type="video/mp4" medium="video" fileSize="1024" duration="62.5" width="640" height="abc" lang="en">
+                    <media:title>Video</media:title>
+                </media:content>
+                <media:thumbnail url="http://example.com/thumb.jpg"/>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let item = &channel.items()[0];
+
+    let media = item.media_ext().unwrap();
+    let contents = media.contents();
+    assert_eq!(contents.len(), 1);
+    let content = &contents[0];
+    assert_eq!(content.url(), Some("http://example.com/video.mp4"));
+    assert_eq!(content.mime_type(), Some("video/mp4"));
+    assert_eq!(content.medium(), Some(Medium::Video));
+    assert_eq!(content.file_size(), Some(1024));
+    assert_eq!(content.duration(), Some(Duration::from_millis(62_500)));
+    assert_eq!(content.width(), Some(640));
+    assert_eq!(content.height(), None);
+    assert_eq!(
+        content.extras().get("height").map(String::as_str),
+        Some("abc")
+    );
+    assert_eq!(content.extras().get("lang").map(String::as_str), Some("en"));
+    assert_eq!(content.children()["title"][0].value(), Some("Video"));
+
+    assert!(item.extensions()["media"].contains_key("thumbnail"));
+    assert!(!item.extensions()["media"].contains_key("content"));
+}
+
+#[test]
+fn read_media_content_by_namespace() {
+    let input = r#"
+    <rss version="2.0" xmlns:media="http://example.com/not-media" xmlns:m="http://search.yahoo.com/mrss">
+        <channel>
+            <item>
+                <media:content url="http://example.com/other.mp4"/>
+            </item>
+            <item>
+                <m:content url="http://example.com/video.mp4"/>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+
+    let item = &channel.items()[0];
+    assert!(item.media_ext().is_none());
+    assert_eq!(
+        item.extensions()["media"]["content"][0].attrs()["url"],
+        "http://example.com/other.mp4"
+    );
+
+    let item = &channel.items()[1];
+    assert_eq!(
+        item.media_ext().unwrap().contents()[0].url(),
+        Some("http://example.com/video.mp4")
+    );
+    assert!(item.extensions().is_empty());
+}
+
+#[test]
+fn read_items_without_channel() {
+    let input = r#"
+    <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/">
+        <item rdf:about="http://example.com/1">
+            <title>First</title>
+            <link>http://example.com/1</link>
+        </item>
+        <item rdf:about="http://example.com/2">
+            <title>Second</title>
+            <link>http://example.com/2</link>
+        </item>
+    </rdf:RDF>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+
+    assert_eq!(channel.title(), "");
+    assert_eq!(channel.items().len(), 2);
+    assert_eq!(channel.items()[0].title(), Some("First"));
+    assert_eq!(channel.items()[1].link(), Some("http://example.com/2"));
+
+    let empty = r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"></rdf:RDF>"#;
+    assert!(empty.parse::<Channel>().is_err());
+}
+
+#[test]
+fn read_xml_declaration() {
+    let input = r#"<?xml version="1.0" encoding="ISO-8859-1" standalone="yes"?>
+    <rss version="2.0">
+        <channel>
+            <title>Title</title>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let declaration = channel.xml_declaration().unwrap();
+    assert_eq!(declaration.version(), "1.0");
+    assert_eq!(declaration.encoding(), Some("ISO-8859-1"));
+    assert_eq!(declaration.standalone(), Some("yes"));
+
+    // the output is always UTF-8
+    let output = channel.to_string();
+    assert!(output.starts_with(r#"<?xml version="1.0" encoding="utf-8" standalone="yes"?>"#));
+
+    let input =
+        r#"<?xml version="1.0" encoding="UTF-8"?><rss version="2.0"><channel></channel></rss>"#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert!(channel
+        .to_string()
+        .starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+
+    let input = r#"<rss version="2.0"><channel></channel></rss>"#;
+    assert!(input
+        .parse::<Channel>()
+        .unwrap()
+        .xml_declaration()
+        .is_none());
+}
+
+#[test]
+fn read_default_xml_declaration() {
+    let input = r#"<?xml version="1.0"?><rss version="2.0"><channel></channel></rss>"#;
+    let channel = input.parse::<Channel>().unwrap();
+    let declaration = channel.xml_declaration().unwrap();
+    assert_eq!(declaration.version(), "1.0");
+    assert_eq!(declaration.encoding(), None);
+
+    let input =
+        r#"<?xml version="1.0" encoding="utf-8"?><rss version="2.0"><channel></channel></rss>"#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(channel.xml_declaration(), Some(&XmlDeclaration::default()));
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn read_promote_atom_enclosures() {
+    let input = r#"
+    <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <item>
+                <atom:link rel="enclosure" href="http://example.com/episode.mp3" type="audio/mpeg" length="1234"/>
+            </item>
+            <item>
+                <enclosure url="http://example.com/other.mp3" type="audio/mpeg" length="10"/>
+                <atom:link rel="enclosure" href="http://example.com/ignored.mp3"/>
+            </item>
+        </channel>
+    </rss>
+    "#;
+
+    let channel = input.parse::<Channel>().unwrap();
+    assert!(channel.items()[0].enclosure().is_none());
+
+    let options = ReadOptions {
+        promote_atom_enclosures: true,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    let enclosure = channel.items()[0].enclosure().unwrap();
+    assert_eq!(enclosure.url(), "http://example.com/episode.mp3");
+    assert_eq!(enclosure.mime_type(), "audio/mpeg");
+    assert_eq!(enclosure.length(), "1234");
+    assert_eq!(
+        channel.items()[1].enclosure().unwrap().url(),
+        "http://example.com/other.mp3"
+    );
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn read_promote_atom_id() {
+    let input = r#"
+    <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <item>
+                <atom:id>urn:uuid:60a76c80-d399-11d9-b93c-0003939e0af6</atom:id>
+            </item>
+            <item>
+                <guid>http://example.com/2</guid>
+                <atom:id>urn:uuid:ignored</atom:id>
+            </item>
+        </channel>
+    </rss>
+    "#;
+
+    let channel = input.parse::<Channel>().unwrap();
+    assert!(channel.items()[0].guid().is_none());
+    assert_eq!(
+        channel.items()[0].atom_ext().unwrap().id(),
+        Some("urn:uuid:60a76c80-d399-11d9-b93c-0003939e0af6")
+    );
+
+    let options = ReadOptions {
+        promote_atom_id: true,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    let guid = channel.items()[0].guid().unwrap();
+    assert_eq!(
+        guid.value(),
+        "urn:uuid:60a76c80-d399-11d9-b93c-0003939e0af6"
+    );
+    assert!(!guid.is_permalink());
+    let guid = channel.items()[1].guid().unwrap();
+    assert_eq!(guid.value(), "http://example.com/2");
+    assert!(guid.is_permalink());
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn read_atom_pagination_links() {
+    let input = r#"
+    <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <atom:link rel="self" href="http://example.com/feed?page=2"/>
+            <atom:link rel="next" href="http://example.com/feed?page=3"/>
+            <atom:link rel="previous" href="http://example.com/feed?page=1"/>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let pagination = channel.atom_ext().unwrap().pagination_links();
+    assert_eq!(pagination.next, Some("http://example.com/feed?page=3"));
+    assert_eq!(pagination.prev, Some("http://example.com/feed?page=1"));
+    assert_eq!(pagination.first, None);
+    assert_eq!(pagination.last, None);
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn read_artwork_url_atom_icon() {
+    let input = r#"
+    <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <atom:link rel="self" href="http://example.com/feed.xml"/>
+            <atom:link rel="icon" href="http://example.com/icon.png"/>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(channel.artwork_url(), Some("http://example.com/icon.png"));
+}
+
+#[test]
+fn read_strict() {
+    let read = |skip: &str, strict: bool| {
+        let input = format!(
+            r#"<rss version="2.0"><channel><ttl>60</ttl>{}</channel></rss>"#,
+            skip
+        );
+        let options = ReadOptions {
+            strict,
+            ..Default::default()
+        };
+        Channel::read_from_with_options(input.as_bytes(), &options)
+    };
+
+    let skip_hours = "<skipHours><hour>99</hour></skipHours>";
+    assert_eq!(read(skip_hours, false).unwrap().skip_hours(), ["99"]);
+    match read(skip_hours, true) {
+        Err(Error::InvalidValue { field, value }) => {
+            assert_eq!(field, "hour");
+            assert_eq!(value, "99");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let skip_days = "<skipDays><day>Someday</day></skipDays>";
+    assert!(read(skip_days, true).is_err());
+
+    let valid = "<skipHours><hour>0</hour><hour>23</hour></skipHours>";
+    assert!(read(valid, true).is_ok());
+}
+
+#[test]
+fn read_strict_fails_at_the_invalid_element() {
+    // the document is truncated after the invalid hour, so only a check made while reading
+    // the element reports it
+    let input = r#"<rss version="2.0"><channel><skipHours><hour>99</hour>"#;
+    let options = ReadOptions {
+        strict: true,
+        ..Default::default()
+    };
+    match Channel::read_from_with_options(input.as_bytes(), &options) {
+        Err(Error::InvalidValue { field, .. }) => assert_eq!(field, "hour"),
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn read_expected_items() {
+    let input = include_str!("data/rss2sample.xml");
+    let options = ReadOptions {
+        expected_items: 64,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(channel.items().len(), 4);
+    assert!(channel.items.capacity() >= 64);
+}
+
+#[test]
+fn read_capture_raw_items() {
+    let input = "<rss version=\"2.0\">\n<channel>\n<item>\n  <title>First &amp; one</title>\n</item>\n<item><guid isPermaLink=\"false\">2</guid></item>\n</channel>\n</rss>";
+    let options = ReadOptions {
+        capture_raw_items: true,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(
+        channel.items()[0].raw_xml.as_deref(),
+        Some("<item>\n  <title>First &amp; one</title>\n</item>")
+    );
+    assert_eq!(
+        channel.items()[1].raw_xml.as_deref(),
+        Some("<item><guid isPermaLink=\"false\">2</guid></item>")
+    );
+
+    // small reads exercise the recording across buffer refills
+    let reader = std::io::BufReader::with_capacity(3, input.as_bytes());
+    let buffered = Channel::read_from_with_options(reader, &options).unwrap();
+    assert_eq!(buffered.items(), channel.items());
+
+    let channel = input.parse::<Channel>().unwrap();
+    assert!(channel.items()[0].raw_xml.is_none());
+    // the raw source does not take part in comparisons
+    assert_eq!(buffered.items(), channel.items());
+}
+
+#[test]
+fn read_lint() {
+    let input = r#"
+    <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <atom:link rel="self" href="http://example.com/feed.xml"/>
+            <item>
+                <title>Title</title>
+                <guid>1</guid>
+            </item>
+            <item>
+                <link>http://example.com/2</link>
+                <guid>2</guid>
+            </item>
+            <item>
+                <content:encoded xmlns:content="http://purl.org/rss/1.0/modules/content/">Content</content:encoded>
+                <guid>3</guid>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    // the HTML content stands in for the description, as in validation
+    assert_eq!(channel.lint(), vec![Lint::ItemWithoutTitleOrDescription(1)]);
+}
+
+#[test]
+fn read_on_unknown_element() {
+    let input = r#"
+    <rss version="2.0" xmlns:foo="http://example.com/foo">
+        <channel>
+            <foo:bar>channel</foo:bar>
+            <item>
+                <title>Title</title>
+                <foo:bar>item</foo:bar>
+                <foo:baz>kept</foo:baz>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let handler_seen = seen.clone();
+    let options = ReadOptions {
+        on_unknown_element: Some(ElementHandler::new(move |namespace, element| {
+            handler_seen.lock().unwrap().push((
+                namespace.to_string(),
+                element.name().to_string(),
+                element.value().map(str::to_string),
+            ));
+            element.name() == "foo:bar"
+        })),
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            (
+                "http://example.com/foo".to_string(),
+                "foo:bar".to_string(),
+                Some("channel".to_string())
+            ),
+            (
+                "http://example.com/foo".to_string(),
+                "foo:bar".to_string(),
+                Some("item".to_string())
+            ),
+            (
+                "http://example.com/foo".to_string(),
+                "foo:baz".to_string(),
+                Some("kept".to_string())
+            ),
+        ]
+    );
+    assert!(channel.extensions().is_empty());
+    let item_ext = &channel.items()[0].extensions()["foo"];
+    assert!(!item_ext.contains_key("bar"));
+    assert_eq!(item_ext["baz"][0].value(), Some("kept"));
+}
+
+#[test]
+fn read_count_items() {
+    for name in [
+        "channel",
+        "item",
+        "rss090",
+        "rss091",
+        "rss092",
+        "rss1",
+        "rss2sample",
+        "itunes",
+        "dublincore",
+        "extension",
+    ] {
+        let path = format!("tests/data/{}.xml", name);
+        let input = std::fs::read(&path).unwrap();
+        let channel = Channel::read_from(&input[..]).unwrap();
+        assert_eq!(
+            Channel::count_items(&input[..]).unwrap(),
+            channel.items().len(),
+            "{}",
+            path
+        );
+    }
+}
+
+#[test]
+fn read_count_items_with_options() {
+    let input = r#"
+    <response>
+        <status>ok</status>
+        <rss xmlns:atom="http://www.w3.org/2005/Atom">
+            <channel>
+                <item/>
+                <atom:entry/>
+                <item/>
+            </channel>
+        </rss>
+    </response>
+    "#;
+    let options = ReadOptions {
+        root_search_depth: 1,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(
+        Channel::count_items_with_options(input.as_bytes(), &options).unwrap(),
+        channel.items().len()
+    );
+    assert_eq!(channel.items().len(), 2);
+    assert!(matches!(
+        Channel::count_items(input.as_bytes()),
+        Err(Error::InvalidStartTag)
+    ));
+}
+
+#[test]
+fn read_itunes_validate_categories() {
+    let input = include_str!("data/itunes.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    assert_eq!(
+        channel.itunes_ext().unwrap().validate_categories(),
+        Err(vec![
+            "Category 1 > Subcategory".to_string(),
+            "Category 2".to_string(),
+        ])
+    );
+
+    let input = r#"
+    <rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+        <channel>
+            <itunes:category text="Health &amp; Fitness">
+                <itunes:category text="Mental Health"/>
+            </itunes:category>
+            <itunes:category text="True Crime"/>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    assert_eq!(channel.itunes_ext().unwrap().validate_categories(), Ok(()));
+}
+
+#[test]
+fn read_enclosure_inferred_mime_type() {
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <item><enclosure url="http://example.com/media.mp3" length="0"/></item>
+            <item><enclosure url="http://example.com/media.WEBM" length="0"/></item>
+            <item><enclosure url="http://example.com/media" length="0"/></item>
+            <item><enclosure url="http://example.com" length="0"/></item>
+            <item><enclosure url="http://media.mp3" length="0"/></item>
+            <item><enclosure url="http://example.com/" length="0"/></item>
+            <item><enclosure url="http://example.com/media.mp3/download" length="0"/></item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let inferred = channel
+        .items()
+        .iter()
+        .map(|item| item.enclosure().unwrap().inferred_mime_type())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        inferred,
+        vec![
+            Some("audio/mpeg"),
+            Some("video/webm"),
+            None,
+            None,
+            None,
+            None,
+            None
+        ]
+    );
+    assert_eq!(channel.items()[0].enclosure().unwrap().mime_type(), "");
+}
+
+#[test]
+fn read_transcript_url_by_namespace() {
+    let input = r#"
+    <rss version="2.0" xmlns:pc="https://podcastindex.org/namespace/1.0">
+        <channel>
+            <item>
+                <pc:transcript url="https://example.com/1.vtt" type="text/vtt"/>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(
+        channel.items()[0].transcript_url(),
+        Some("https://example.com/1.vtt")
+    );
+
+    let output = channel.to_string();
+    assert!(output.contains(r#"xmlns:pc="https://podcastindex.org/namespace/1.0""#));
+    assert!(output.contains("<pc:transcript "));
+    assert!(!output.contains("xmlns:podcast"));
+
+    let input = r#"
+    <rss version="2.0" xmlns:podcast="http://example.com/not-podcast">
+        <channel>
+            <item>
+                <podcast:transcript url="https://example.com/1.vtt" type="text/vtt"/>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(channel.items()[0].transcript_url(), None);
+}
+
+#[test]
+fn read_channel_metadata() {
+    let input = include_str!("data/rss2sample.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    let metadata = channel.metadata();
+    assert_eq!(metadata.title, channel.title());
+    assert_eq!(metadata.link, channel.link());
+    assert_eq!(metadata.description, channel.description());
+    assert_eq!(metadata.language, Some("en-us"));
+    assert_eq!(metadata.generator, channel.generator());
+    assert_eq!(metadata.docs, channel.docs());
+    assert_eq!(metadata.last_build_date, channel.last_build_date());
+    #[cfg(feature = "validation")]
+    assert!(metadata.last_build_date_parsed.is_some());
+}
+
+#[test]
+fn read_utf8_bom() {
+    let input = include_str!("data/rss2sample.xml");
+    let expected = Channel::read_from(input.as_bytes()).unwrap();
+
+    let mut with_bom = b"\xEF\xBB\xBF".to_vec();
+    with_bom.extend_from_slice(input.as_bytes());
+    assert_eq!(Channel::read_from(&with_bom[..]).unwrap(), expected);
+
+    // the BOM split across reads
+    let reader = std::io::BufReader::with_capacity(1, &with_bom[..]);
+    assert_eq!(Channel::read_from(reader).unwrap(), expected);
+
+    let with_bom = format!("\u{FEFF}{}", input);
+    assert_eq!(with_bom.parse::<Channel>().unwrap(), expected);
+}
+
+#[test]
+fn read_normalized_categories() {
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <item>
+                <category>Rust</category>
+                <category>rust</category>
+                <category domain="http://example.com/tags">rust</category>
+                <category>XML</category>
+            </item>
+            <item>
+                <category>Feeds</category>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let categories = channel.items()[0].normalized_categories();
+    assert_eq!(categories.len(), 3);
+    assert_eq!(categories[0].name(), "Rust");
+    assert_eq!(categories[1].domain(), Some("http://example.com/tags"));
+    assert_eq!(categories[2].name(), "XML");
+
+    let names = channel.collect_category_names();
+    assert_eq!(
+        names.into_iter().collect::<Vec<_>>(),
+        ["feeds", "rust", "xml"]
+    );
+}
+
+#[test]
+fn read_itunes_numbers() {
+    let input = include_str!("data/itunes.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    let ext = channel.items()[0].itunes_ext().unwrap();
+
+    assert_eq!(ext.order_num(), Some(Ok(1)));
+    assert_eq!(ext.episode_num(), Some(Ok(2)));
+    assert_eq!(ext.season_num(), Some(Ok(3)));
+}
+
+#[test]
+fn read_all_extensions() {
+    let input = include_str!("data/extension.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    let extensions = channel
+        .all_extensions()
+        .map(|(prefix, name, extension)| (prefix, name, extension.value()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        extensions,
+        vec![
+            ("ext", "contributor", Some("Contributor 1")),
+            ("ext", "contributor", Some("Contributor 2")),
+            ("ext", "creator", Some("Creator Name")),
+            ("ext", "parent", None),
+        ]
+    );
+}
+
+#[test]
+fn read_leading_text() {
+    let feed =
+        r#"<?xml version="1.0"?><rss version="2.0"><channel><title>Title</title></channel></rss>"#;
+    let expected = feed.parse::<Channel>().unwrap();
+    for prefix in [
+        "\n\n  ",
+        "HTTP/1.1 200 OK\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Type: text/xml; a & b\r\n\r\n",
+    ] {
+        let input = format!("{}{}", prefix, feed);
+        assert_eq!(input.parse::<Channel>().unwrap(), expected, "{:?}", prefix);
+    }
+
+    let input = format!("<html>{}", feed);
+    assert!(matches!(input.parse::<Channel>(), Err(Error::NotAFeed)));
+}
+
+#[test]
+fn read_leading_text_limit() {
+    let feed = "<rss version=\"2.0\"><channel><title>Title</title></channel></rss>";
+
+    let input = format!("{}{}", "x".repeat(4096), feed);
+    assert!(input.parse::<Channel>().is_ok());
+
+    let input = format!("{}{}", "x".repeat(4097), feed);
+    assert!(matches!(
+        input.parse::<Channel>(),
+        Err(Error::InvalidStartTag)
+    ));
+    assert!(matches!(
+        Channel::count_items(input.as_bytes()),
+        Err(Error::InvalidStartTag)
+    ));
+
+    // the text inside wrapper elements is their content, not leading text
+    let input = format!("<response>{}{}</response>", "x".repeat(8192), feed);
+    let options = ReadOptions {
+        root_search_depth: 1,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(channel.title(), "Title");
+}
+
+#[test]
+fn read_html_document() {
+    for input in [
+        "<!DOCTYPE html><html><head><title>Not found</title></head></html>",
+        "<!doctype HTML PUBLIC \"-//W3C//DTD HTML 4.01//EN\"><html></html>",
+        "\n<HTML><body>Moved</body></HTML>",
+    ] {
+        let result = input.parse::<Channel>();
+        assert!(matches!(result, Err(Error::NotAFeed)), "{:?}", input);
+        assert!(matches!(
+            Channel::count_items(input.as_bytes()),
+            Err(Error::NotAFeed)
+        ));
+    }
+
+    let err = "<html></html>".parse::<Channel>().unwrap_err();
+    assert_eq!(err.to_string(), "the input is an HTML document, not a feed");
+    assert!(matches!(
+        "<feed></feed>".parse::<Channel>(),
+        Err(Error::InvalidStartTag)
+    ));
+}
+
+#[test]
+fn read_feed_type() {
+    for (name, format) in [
+        ("rss090", FeedFormat::Rss09),
+        ("rss091", FeedFormat::Rss09),
+        ("rss092", FeedFormat::Rss09),
+        ("rss1", FeedFormat::Rss1),
+        ("rss2sample", FeedFormat::Rss2),
+        ("rss2_with_atom", FeedFormat::Rss2),
+        ("channel", FeedFormat::Rss2),
+        ("itunes", FeedFormat::Rss2),
+    ] {
+        let path = format!("tests/data/{}.xml", name);
+        let input = std::fs::read(&path).unwrap();
+        let channel = Channel::read_from(&input[..]).unwrap();
+        assert_eq!(channel.feed_type(), format, "{}", path);
+    }
+}
+
+#[test]
+fn read_best_artwork() {
+    let input = r#"
+    <rss version="2.0" xmlns:m="http://search.yahoo.com/mrss"
+        xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+        <channel>
+            <image>
+                <url>http://example.com/image.png</url>
+                <title>Image</title>
+                <link>http://example.com</link>
+                <width>144</width>
+            </image>
+            <itunes:image href="http://example.com/itunes.jpg"/>
+            <m:thumbnail url="http://example.com/thumb-1000.jpg" width="1000" height="750"/>
+            <m:thumbnail url="http://example.com/thumb-120.jpg" width="120" height="90"/>
+            <m:thumbnail url="http://example.com/thumb-480.jpg" width="480" height="360"/>
+            <m:thumbnail url="http://example.com/thumb-unsized.jpg"/>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(
+        channel.best_artwork(300),
+        Some("http://example.com/thumb-480.jpg")
+    );
+    assert_eq!(
+        channel.best_artwork(480),
+        Some("http://example.com/thumb-480.jpg")
+    );
+    assert_eq!(
+        channel.best_artwork(130),
+        Some("http://example.com/image.png")
+    );
+    assert_eq!(
+        channel.best_artwork(100),
+        Some("http://example.com/thumb-120.jpg")
+    );
+    assert_eq!(
+        channel.best_artwork(1200),
+        Some("http://example.com/itunes.jpg")
+    );
+    assert_eq!(
+        channel.best_artwork(5000),
+        Some("http://example.com/itunes.jpg")
+    );
+    assert_eq!(Channel::default().best_artwork(100), None);
+}
+
+#[test]
+fn read_media_content_thumbnails() {
+    let input = r#"
+    <rss version="2.0" xmlns:media="http://search.yahoo.com/mrss/">
+        <channel>
+            <item>
+                <media:content url="http://example.com/video.mp4">
+                    <media:thumbnail url="http://example.com/thumb.jpg" width="320" height="x" time="12:05:01.123"/>
+                </media:content>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let media = channel.items()[0].media_ext().unwrap();
+    let content = &media.contents()[0];
+    let thumbnails = content.thumbnails();
+    assert_eq!(thumbnails.len(), 1);
+    assert_eq!(thumbnails[0].url(), "http://example.com/thumb.jpg");
+    assert_eq!(thumbnails[0].width(), Some(320));
+    assert_eq!(thumbnails[0].height(), None);
+    assert_eq!(thumbnails[0].time(), Some("12:05:01.123"));
+    assert_eq!(
+        thumbnails[0].extras().get("height").map(String::as_str),
+        Some("x")
+    );
+}
+
+#[test]
+fn read_extension_fragment() {
+    let extensions = parse_fragment(r#"<x:tag a="1"><x:child>v</x:child></x:tag>"#).unwrap();
+    let child = Extension {
+        name: "x:child".to_string(),
+        value: Some("v".to_string()),
+        ..Default::default()
+    };
+    let expected = Extension {
+        name: "x:tag".to_string(),
+        attrs: BTreeMap::from([("a".to_string(), "1".to_string())]),
+        children: BTreeMap::from([("child".to_string(), vec![child])]),
+        ..Default::default()
+    };
+    assert_eq!(extensions, vec![expected]);
+
+    let extensions = parse_fragment("<a/>\n<b>text</b>").unwrap();
+    assert_eq!(extensions.len(), 2);
+    assert_eq!(extensions[0].name(), "a");
+    assert_eq!(extensions[1].value(), Some("text"));
+
+    assert!(parse_fragment("<a><b></a>").is_err());
+}
+
+#[test]
+fn read_preserve_unknown_entities() {
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <title>Fish &amp; chips &mdash; daily</title>
+            <item>
+                <description>One &mdash; two &lt;b&gt; &#8212;</description>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    assert!(input.parse::<Channel>().is_err());
+
+    let options = ReadOptions {
+        preserve_unknown_entities: true,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(channel.title(), "Fish & chips &mdash; daily");
+    assert_eq!(
+        channel.items()[0].description(),
+        Some("One &mdash; two <b> \u{2014}")
+    );
+}
+
+#[test]
+fn read_trim_text() {
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <title>Title</title>
+            <description> a <![CDATA[b]]> c </description>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(channel.description(), "a b c");
+
+    let options = ReadOptions {
+        trim_text: true,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(channel.description(), "abc");
+    assert_eq!(channel.title(), "Title");
+}
+
+#[test]
+fn read_is_podcast() {
+    let input = include_str!("data/itunes.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    assert!(channel.is_podcast());
+    assert_eq!(channel.podcast_kind(), Some("episodic"));
+
+    let input = include_str!("data/rss2sample.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    assert!(!channel.is_podcast());
+    assert_eq!(channel.podcast_kind(), None);
+
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <item>
+                <enclosure url="http://example.com/a.jpg" length="1" type="image/jpeg"/>
+            </item>
+            <item>
+                <enclosure url="http://example.com/b" length="1" type="Video/MP4"/>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert!(channel.is_podcast());
+}
+
+#[test]
+fn read_channel_diff() {
+    let previous = r#"
+    <rss version="2.0">
+        <channel>
+            <item><guid>1</guid><title>One</title></item>
+            <item><guid>2</guid><title>Two</title></item>
+            <item><link>http://example.com/3</link><title>Three</title></item>
+            <item><title>No identity</title></item>
+        </channel>
+    </rss>
+    "#;
+    let current = r#"
+    <rss version="2.0">
+        <channel>
+            <item><guid>4</guid><title>Four</title></item>
+            <item><link>http://example.com/3</link><title>Three, updated</title></item>
+            <item><guid>1</guid><title>One</title></item>
+            <item><title>No identity</title></item>
+            <item><title>Also no identity</title></item>
+        </channel>
+    </rss>
+    "#;
+    let previous = previous.parse::<Channel>().unwrap();
+    let current = current.parse::<Channel>().unwrap();
+    let diff = current.diff(&previous);
+
+    let titles = |items: &[&Item]| {
+        items
+            .iter()
+            .map(|item| item.title().unwrap_or_default().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(titles(&diff.added), ["Four", "Also no identity"]);
+    assert_eq!(titles(&diff.removed), ["Two"]);
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(diff.changed[0].0.title(), Some("Three, updated"));
+    assert_eq!(diff.changed[0].1.title(), Some("Three"));
+
+    let diff = current.diff(&current);
+    assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty());
+}
+
+#[test]
+fn read_channel_diff_duplicates() {
+    let previous = r#"
+    <rss version="2.0">
+        <channel>
+            <item><guid>1</guid><title>First</title></item>
+            <item><guid>1</guid><title>Second</title></item>
+            <item><title>No identity</title></item>
+        </channel>
+    </rss>
+    "#;
+    let current = r#"
+    <rss version="2.0">
+        <channel>
+            <item><guid>1</guid><title>First</title></item>
+            <item><guid>1</guid><title>Second, updated</title></item>
+            <item><guid>1</guid><title>Third</title></item>
+            <item><title>No identity</title></item>
+            <item><title>No identity</title></item>
+        </channel>
+    </rss>
+    "#;
+    let previous = previous.parse::<Channel>().unwrap();
+    let current = current.parse::<Channel>().unwrap();
+    let diff = current.diff(&previous);
+
+    let titles = |items: &[&Item]| {
+        items
+            .iter()
+            .map(|item| item.title().unwrap_or_default().to_string())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(titles(&diff.added), ["Third", "No identity"]);
+    assert!(diff.removed.is_empty());
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(diff.changed[0].0.title(), Some("Second, updated"));
+    assert_eq!(diff.changed[0].1.title(), Some("Second"));
+
+    let diff = previous.diff(&current);
+    assert!(diff.added.is_empty());
+    assert_eq!(titles(&diff.removed), ["Third", "No identity"]);
+}
+
+#[test]
+fn read_skip_hours_unknown_children() {
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <skipHours>
+                <hour>1</hour>
+                <minute>30<extra>nested</extra></minute>
+                <!-- comment -->
+                <hour>2<b>bold</b></hour>
+                <ns:hour xmlns:ns="http://example.com/">3</ns:hour>
+                <empty/>
+                <hour>4</hour>
+            </skipHours>
+            <skipDays>
+                <unknown><day>Sunday</day></unknown>
+                <day>Monday</day>
+            </skipDays>
+            <title>Title</title>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(channel.skip_hours(), ["1", "2", "4"]);
+    assert_eq!(channel.skip_days(), ["Monday"]);
+    assert_eq!(channel.title(), "Title");
+
+    let input = "<rss><channel><skipHours><hour>1</hour><minute>";
+    assert!(input.parse::<Channel>().is_err());
+
+    let input = "<rss><channel><skipDays><day>Monday</day>";
+    assert!(matches!(input.parse::<Channel>(), Err(Error::Eof)));
+
+    let input = "<rss><channel><skipHours><hour>1</minute></skipHours></channel></rss>";
+    assert!(input.parse::<Channel>().is_err());
+}
+
+#[test]
+fn read_atom_links_raw() {
+    let input = r#"
+    <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <atom:link rel="self" href="http://example.com/feed.xml"/>
+            <atom:link href="http://example.com/"/>
+            <atom:link rel="hub"/>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(
+        channel.atom_links_raw(),
+        [
+            ("http://example.com/feed.xml", "self"),
+            ("http://example.com/", "alternate"),
+        ]
+    );
+}
+
+#[test]
+fn read_source_url_from_text() {
+    let input = r#"
+        <rss version="2.0">
+            <channel>
+                <item><source url="http://example.com/feed/">Example Feed</source></item>
+                <item><source> http://example.com/other/ </source></item>
+                <item><source>Example Feed: http://example.com/</source></item>
+            </channel>
+        </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let sources = channel
+        .items()
+        .iter()
+        .map(|item| {
+            let source = item.source().unwrap();
+            (source.url(), source.title())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sources,
+        vec![
+            ("http://example.com/feed/", Some("Example Feed")),
+            ("http://example.com/other/", None),
+            ("", Some("Example Feed: http://example.com/")),
+        ]
+    );
+}
+
+#[test]
+fn read_partial() {
+    let input = include_str!("data/rss2sample.xml");
+    let (channel, error) = Channel::read_from_partial(input.as_bytes());
+    assert!(error.is_none());
+    assert_eq!(channel, input.parse::<Channel>().unwrap());
+
+    let truncated = r#"
+        <rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+            <channel>
+                <title>Title</title>
+                <dc:creator>Creator</dc:creator>
+                <item><title>First</title></item>
+                <item><title>Second</title></item>
+                <item><title>Thi"#;
+    assert!(truncated.parse::<Channel>().is_err());
+
+    let (channel, error) = Channel::read_from_partial(truncated.as_bytes());
+    assert!(error.is_some());
+    assert_eq!(channel.title(), "Title");
+    assert_eq!(
+        channel.dublin_core_ext().map(|ext| ext.creators()),
+        Some(&["Creator".to_string()][..])
+    );
+    assert_eq!(
+        channel
+            .items()
+            .iter()
+            .map(|item| item.title())
+            .collect::<Vec<_>>(),
+        vec![Some("First"), Some("Second")]
+    );
+    assert!(channel.namespaces().contains_key("dc"));
+
+    let (channel, error) = Channel::read_from_partial("<html></html>".as_bytes());
+    assert!(matches!(error, Some(Error::NotAFeed)));
+    assert_eq!(channel, Channel::default());
+}
+
+#[test]
+fn read_utf16() {
+    let input = r#"<?xml version="1.0" encoding="UTF-16"?>
+        <rss version="2.0">
+            <channel>
+                <title>Café 日本</title>
+                <item><title>📰 News</title></item>
+            </channel>
+        </rss>"#;
+    let le = input
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect::<Vec<_>>();
+    let be = [0xFE, 0xFF]
+        .into_iter()
+        .chain(input.encode_utf16().flat_map(u16::to_be_bytes))
+        .collect::<Vec<_>>();
+
+    for bytes in [le, be] {
+        let channel = Channel::read_from(&bytes[..]).unwrap();
+        assert_eq!(channel.title(), "Café 日本");
+        assert_eq!(channel.items()[0].title(), Some("📰 News"));
+        assert_eq!(
+            channel.xml_declaration().and_then(|decl| decl.encoding()),
+            Some("UTF-16")
+        );
+    }
+}
+
+#[test]
+fn read_unsupported_encoding() {
+    let input = r#"<?xml version="1.0" encoding="x-unknown"?><rss><channel></channel></rss>"#;
+    match input.parse::<Channel>() {
+        Err(Error::UnsupportedEncoding(encoding)) => assert_eq!(encoding, "x-unknown"),
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    let input = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\
+        <rss><channel><title>Caf\xe9</title></channel></rss>";
+    let channel = Channel::read_from(&input[..]).unwrap();
+    assert_eq!(channel.title(), "Café");
+}
+
+#[test]
+fn read_sort_items_by_itunes_order() {
+    let input = r#"
+        <rss version="2.0" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd">
+            <channel>
+                <item><title>A</title></item>
+                <item><title>B</title><itunes:order>2</itunes:order></item>
+                <item><title>C</title><itunes:order>1</itunes:order></item>
+                <item><title>D</title><itunes:order>last</itunes:order></item>
+                <item><title>E</title><itunes:order> 3 </itunes:order></item>
+            </channel>
+        </rss>
+    "#;
+    let mut channel = input.parse::<Channel>().unwrap();
+    channel.sort_items_by_itunes_order();
+    let titles = channel
+        .items()
+        .iter()
+        .map(|item| item.title().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["C", "B", "E", "A", "D"]);
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn read_atom_link_extras() {
+    let input = r#"
+        <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+            <channel>
+                <atom:link href="http://example.com/feed" rel="self" data-priority="1"/>
+                <atom:link href="http://example.com/" rel="alternate"/>
+                <item>
+                    <atom:link href="http://example.com/1" rel="related" x:y="z"
+                        xmlns:x="http://example.com/ns"/>
+                </item>
+            </channel>
+        </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let atom = channel.atom_ext().unwrap();
+    assert_eq!(atom.links().len(), 2);
+    assert_eq!(
+        atom.links()[0]
+            .extra_attrs()
+            .get("data-priority")
+            .map(String::as_str),
+        Some("1")
+    );
+    assert!(atom.links()[1].extra_attrs().is_empty());
+    let item_atom = channel.items()[0].atom_ext().unwrap();
+    assert_eq!(
+        item_atom.links()[0]
+            .extra_attrs()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec!["x:y", "xmlns:x"]
+    );
+
+    let output = channel.to_string();
+    assert!(output
+        .contains(r#"<atom:link href="http://example.com/feed" rel="self" data-priority="1"/>"#));
+    assert!(output.contains(r#"<atom:link href="http://example.com/" rel="alternate"/>"#));
+    assert_eq!(
+        output.parse::<Channel>().unwrap().atom_ext(),
+        channel.atom_ext()
+    );
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn read_atom_link_extras_same_href() {
+    let input = r#"
+        <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+            <channel>
+                <atom:link href="http://example.com/feed" rel="alternate" data-priority="1"/>
+                <atom:link href="http://example.com/feed" rel="self" data-priority="2"/>
+                <atom:link href="http://example.com/feed" rel="hub"/>
+            </channel>
+        </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let priorities = channel
+        .atom_ext()
+        .unwrap()
+        .links()
+        .iter()
+        .map(|link| link.extra_attrs().get("data-priority").map(String::as_str))
+        .collect::<Vec<_>>();
+    assert_eq!(priorities, vec![Some("1"), Some("2"), None]);
+
+    let output = channel.to_string();
+    assert!(output.contains(
+        r#"<atom:link href="http://example.com/feed" rel="alternate" data-priority="1"/>"#
+    ));
+    assert!(output
+        .contains(r#"<atom:link href="http://example.com/feed" rel="self" data-priority="2"/>"#));
+    assert!(output.contains(r#"<atom:link href="http://example.com/feed" rel="hub"/>"#));
+    assert_eq!(
+        output.parse::<Channel>().unwrap().atom_ext(),
+        channel.atom_ext()
+    );
+}
+
+#[test]
+fn read_entity_set() {
+    let input = r#"
+        <rss version="2.0">
+            <channel>
+                <title>A&nbsp;B &copy; C&#160;D &amp; &mdash;</title>
+            </channel>
+        </rss>
+    "#;
+    assert!(matches!(input.parse::<Channel>(), Err(Error::Xml(_))));
+
+    let options = ReadOptions {
+        preserve_unknown_entities: true,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(channel.title(), "A&nbsp;B &copy; C\u{a0}D & &mdash;");
+
+    let options = ReadOptions {
+        entity_set: EntitySet::Html5,
+        ..Default::default()
+    };
+    let result = Channel::read_from_with_options(input.as_bytes(), &options);
+    #[cfg(feature = "html-entities")]
+    assert_eq!(
+        result.unwrap().title(),
+        "A\u{a0}B \u{a9} C\u{a0}D & \u{2014}"
+    );
+    #[cfg(not(feature = "html-entities"))]
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_entity_set_other_text() {
+    // only the text of channel and item elements resolves HTML5 entities
+    let input = r#"
+        <rss version="2.0" xmlns:ext="http://example.com/ext">
+            <channel>
+                <title>Title</title>
+                <ext:note>A&nbsp;B</ext:note>
+            </channel>
+        </rss>
+    "#;
+    assert!(matches!(input.parse::<Channel>(), Err(Error::Xml(_))));
+    let options = ReadOptions {
+        entity_set: EntitySet::Html5,
+        ..Default::default()
+    };
+    assert!(matches!(
+        Channel::read_from_with_options(input.as_bytes(), &options),
+        Err(Error::Xml(_))
+    ));
+}
+
+#[test]
+fn read_nested_root() {
+    let input = r#"<?xml version="1.0"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/">
+    <soap:Header><auth><token>secret</token></auth></soap:Header>
+    <soap:Body>
+        <response>
+            <rss version="2.0"><channel><title>Wrapped</title><item/></channel></rss>
+        </response>
+    </soap:Body>
+</soap:Envelope>"#;
+
+    assert!(matches!(
+        Channel::read_from(input.as_bytes()),
+        Err(Error::InvalidStartTag)
+    ));
+
+    let options = ReadOptions {
+        root_search_depth: 2,
+        ..Default::default()
+    };
+    assert!(matches!(
+        Channel::read_from_with_options(input.as_bytes(), &options),
+        Err(Error::Eof)
+    ));
+
+    let options = ReadOptions {
+        root_search_depth: 3,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(channel.title(), "Wrapped");
+    assert_eq!(channel.items().len(), 1);
+}
+
+#[test]
+fn read_nested_root_namespaces() {
+    let input = r#"
+<response xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <other xmlns:ext="http://example.com/ext"/>
+    <rss version="2.0">
+        <channel>
+            <title>Wrapped</title>
+            <dc:creator>Creator</dc:creator>
+            <ext:meta>Not in scope</ext:meta>
+        </channel>
+    </rss>
+</response>"#;
+    let options = ReadOptions {
+        root_search_depth: 1,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(
+        channel.dublin_core_ext().unwrap().creators(),
+        ["Creator".to_string()]
+    );
+    assert_eq!(
+        channel.namespaces().get("dc").map(String::as_str),
+        Some("http://purl.org/dc/elements/1.1/")
+    );
+    assert!(!channel.namespaces().contains_key("ext"));
+    assert_eq!(
+        Channel::count_items_with_options(input.as_bytes(), &options).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn read_itunes_from_map_empty_elements() {
+    use rss::extension::itunes::{ITunesChannelExtension, ITunesItemExtension};
+
+    let keys = [
+        "author",
+        "block",
+        "category",
+        "image",
+        "explicit",
+        "complete",
+        "new-feed-url",
+        "owner",
+        "subtitle",
+        "summary",
+        "keywords",
+        "type",
+        "duration",
+        "closedCaptioned",
+        "order",
+        "episode",
+        "season",
+        "episodeType",
+        "title",
+    ];
+    let empty = keys
+        .iter()
+        .map(|key| (key.to_string(), Vec::new()))
+        .collect::<BTreeMap<_, _>>();
+    assert_eq!(
+        ITunesChannelExtension::from_map(empty.clone()),
+        ITunesChannelExtension::default()
+    );
+    assert_eq!(
+        ITunesItemExtension::from_map(empty),
+        ITunesItemExtension::default()
+    );
+
+    let mut owner = Extension::default();
+    owner.children.insert("name".to_string(), Vec::new());
+    owner.children.insert("email".to_string(), Vec::new());
+    let mut category = Extension::default();
+    category.children.insert("category".to_string(), Vec::new());
+    let mut map = BTreeMap::new();
+    map.insert("owner".to_string(), vec![owner]);
+    map.insert("category".to_string(), vec![category]);
+
+    let extension = ITunesChannelExtension::from_map(map);
+    let owner = extension.owner().unwrap();
+    assert_eq!(owner.name(), None);
+    assert_eq!(owner.email(), None);
+    assert_eq!(extension.categories().len(), 1);
+    assert!(extension.categories()[0].subcategory().is_none());
+}
+
+#[test]
+fn read_normalize() {
+    let first = r#"<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:foo="http://example.com/foo">
+<channel>
+    <title>
+        Example
+    </title>
+    <link> http://example.com/ </link>
+    <description>Description</description>
+    <pubDate> Sun, 01 Jan 2017 12:00:00 GMT </pubDate>
+    <category domain="b">News</category>
+    <category>Tech</category>
+    <category domain="a"> Rust </category>
+    <foo:tag>2</foo:tag>
+    <foo:tag>1</foo:tag>
+    <item>
+        <title> Item </title>
+        <guid isPermaLink="false"> 1 </guid>
+        <category>y</category>
+        <category>x</category>
+        <foo:tag attr="b"/>
+        <foo:tag attr="a"><foo:child>2</foo:child><foo:child>1</foo:child></foo:tag>
+    </item>
+</channel>
+</rss>"#;
+    let second = r#"<rss version="2.0" xmlns:foo="http://example.com/foo" xmlns:dc="http://purl.org/dc/elements/1.1/"><channel><title>Example</title><link>http://example.com/</link><description>Description</description><pubDate>Sun, 01 Jan 2017 12:00:00 GMT</pubDate><category>Tech</category><category domain="a">Rust</category><category domain="b">News</category><foo:tag>1</foo:tag><foo:tag>2</foo:tag><item><title>Item</title><guid isPermaLink="false">1</guid><category>x</category><category>y</category><foo:tag attr="a"><foo:child>1</foo:child><foo:child>2</foo:child></foo:tag><foo:tag attr="b"/></item></channel></rss>"#;
+
+    let mut first = first.parse::<Channel>().unwrap();
+    let mut second = second.parse::<Channel>().unwrap();
+    assert_ne!(first, second);
+
+    first.normalize();
+    second.normalize();
+    assert_eq!(first, second);
+    assert_eq!(first.title(), "Example");
+    let categories = first
+        .categories()
+        .iter()
+        .map(|category| (category.domain(), category.name()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        [(None, "Tech"), (Some("a"), "Rust"), (Some("b"), "News")]
+    );
+    assert_eq!(
+        get_extension_values(first.extensions().get("foo").unwrap(), "tag"),
+        Some(vec!["1", "2"])
+    );
+}
+
+#[test]
+fn read_normalize_nested_fields() {
+    let first = r#"<rss version="2.0"><channel>
+    <title>Example</title>
+    <link>http://example.com/</link>
+    <description>Description</description>
+    <image>
+        <url> http://example.com/image.png </url>
+        <title> Image </title>
+        <link> http://example.com/ </link>
+        <width> 88 </width>
+    </image>
+    <textInput>
+        <title> Search </title>
+        <description> Search the site </description>
+        <name> q </name>
+        <link> http://example.com/search </link>
+    </textInput>
+    <cloud domain=" example.com " port=" 80 " path=" /rpc " registerProcedure=" notify " protocol=" xml-rpc "/>
+    <item>
+        <enclosure url=" http://example.com/a.mp3 " length=" 1 " type=" audio/mpeg "/>
+        <source url=" http://example.com/feed "> Feed </source>
+    </item>
+</channel></rss>"#;
+    let second = r#"<rss version="2.0"><channel><title>Example</title><link>http://example.com/</link><description>Description</description><image><url>http://example.com/image.png</url><title>Image</title><link>http://example.com/</link><width>88</width></image><textInput><title>Search</title><description>Search the site</description><name>q</name><link>http://example.com/search</link></textInput><cloud domain="example.com" port="80" path="/rpc" registerProcedure="notify" protocol="xml-rpc"/><item><enclosure url="http://example.com/a.mp3" length="1" type="audio/mpeg"/><source url="http://example.com/feed">Feed</source></item></channel></rss>"#;
+
+    let mut first = first.parse::<Channel>().unwrap();
+    let mut second = second.parse::<Channel>().unwrap();
+    assert_ne!(first, second);
+
+    first.normalize();
+    second.normalize();
+    assert_eq!(first, second);
+    assert_eq!(first.image().unwrap().width(), Some("88"));
+    assert_eq!(first.cloud().unwrap().protocol(), "xml-rpc");
+    assert_eq!(
+        first.items()[0].enclosure().unwrap().url(),
+        "http://example.com/a.mp3"
+    );
+}
+
+#[test]
+fn read_normalize_namespaces() {
+    let first = r#"<rss version="2.0" xmlns:foo="http://example.com/ns" xmlns:bar="http://example.com/ns">
+<channel>
+    <foo:tag>1</foo:tag>
+    <bar:tag>2</bar:tag>
+    <item>
+        <foo:tag foo:attr="x"><foo:child>1</foo:child></foo:tag>
+    </item>
+    <item xmlns:foo="http://example.com/other">
+        <foo:tag>Other</foo:tag>
+    </item>
+</channel>
+</rss>"#;
+    let second = r#"<rss version="2.0" xmlns:bar="http://example.com/ns" xmlns:foo="http://example.com/other">
+<channel>
+    <bar:tag>2</bar:tag>
+    <bar:tag>1</bar:tag>
+    <item>
+        <bar:tag bar:attr="x"><bar:child>1</bar:child></bar:tag>
+    </item>
+    <item>
+        <foo:tag>Other</foo:tag>
+    </item>
+</channel>
+</rss>"#;
+
+    let mut first = first.parse::<Channel>().unwrap();
+    let mut second = second.parse::<Channel>().unwrap();
+    first.normalize();
+    second.namespaces.remove("foo");
+    second.normalize();
+    assert_eq!(first, second);
+
+    assert_eq!(first.namespaces().len(), 1);
+    assert_eq!(
+        get_extension_values(first.extensions().get("bar").unwrap(), "tag"),
+        Some(vec!["1", "2"])
+    );
+    let item = &first.items()[0];
+    assert_eq!(
+        item.namespaces().get("bar").map(String::as_str),
+        Some("http://example.com/ns")
+    );
+    let tag = &item.extensions()["bar"]["tag"][0];
+    assert_eq!(tag.name(), "bar:tag");
+    assert_eq!(tag.attrs().get("bar:attr").map(String::as_str), Some("x"));
+    assert_eq!(tag.children()["child"][0].name(), "bar:child");
+    // an item that binds the prefix to another namespace is left unchanged
+    assert_eq!(
+        first.items()[1].extensions()["foo"]["tag"][0].value(),
+        Some("Other")
+    );
+
+    let output = first.to_string();
+    assert!(output.contains(r#"<bar:tag bar:attr="x"><bar:child>1</bar:child></bar:tag>"#));
+    let parsed = output.parse::<Channel>().unwrap();
+    assert_eq!(
+        parsed.items()[1]
+            .namespaces()
+            .get("foo")
+            .map(String::as_str),
+        Some("http://example.com/other")
+    );
+}
+
+#[cfg(feature = "validation")]
+#[test]
+fn read_normalize_dates() {
+    let mut item = Item::default();
+    item.set_pub_date(" Sun, 1 Jan 2017 12:00:00 GMT ".to_string());
+    let mut channel = Channel::default();
+    channel.set_pub_date("2017-01-01T12:00:00Z".to_string());
+    channel.set_last_build_date("yesterday".to_string());
+    channel.set_items(vec![item]);
+
+    channel.normalize();
+    assert_eq!(channel.pub_date(), Some("Sun, 1 Jan 2017 12:00:00 +0000"));
+    assert_eq!(channel.last_build_date(), Some("yesterday"));
+    assert_eq!(
+        channel.items()[0].pub_date(),
+        Some("Sun, 1 Jan 2017 12:00:00 +0000")
+    );
+}
+
+#[cfg(feature = "validation")]
+#[test]
+fn read_channel_age() {
+    use chrono::{Duration, TimeZone, Utc};
+
+    let now = Utc.with_ymd_and_hms(2017, 1, 2, 12, 0, 0).unwrap();
+    let mut channel = Channel::default();
+    assert_eq!(channel.age_at(now), None);
+
+    channel.set_pub_date("2017-01-01T12:00:00Z".to_string());
+    assert_eq!(channel.age_at(now), Some(Duration::days(1)));
+
+    channel.set_last_build_date("Mon, 02 Jan 2017 06:00:00 EST".to_string());
+    assert_eq!(channel.age_at(now), Some(Duration::hours(1)));
+
+    channel.set_last_build_date("yesterday".to_string());
+    assert_eq!(channel.age_at(now), Some(Duration::days(1)));
+
+    channel.set_pub_date(None);
+    assert_eq!(channel.age_at(now), None);
+    assert!(channel.age().is_none());
+}
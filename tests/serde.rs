@@ -0,0 +1,38 @@
+#![cfg(feature = "with-serde")]
+
+extern crate rss;
+extern crate serde;
+
+use rss::extension::syndication::UpdatePeriod;
+use serde::de::value::{Error, StrDeserializer};
+use serde::de::IntoDeserializer;
+use serde::Deserialize;
+
+fn update_period(value: &str) -> Result<UpdatePeriod, Error> {
+    let deserializer: StrDeserializer<Error> = value.into_deserializer();
+    UpdatePeriod::deserialize(deserializer)
+}
+
+#[test]
+fn update_period_lowercase() {
+    assert_eq!(update_period("weekly").unwrap(), UpdatePeriod::Weekly);
+    assert_eq!(
+        update_period("hourly").unwrap(),
+        "hourly".parse::<UpdatePeriod>().unwrap()
+    );
+    assert!(update_period("Weekly").is_err());
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn atom_extension_without_categories_and_authors() {
+    use rss::extension::atom::AtomExtension;
+    use serde::de::value::MapDeserializer;
+
+    // the fields of an `AtomExtension` serialized by 2.0
+    let fields = vec![("links", Vec::<String>::new())];
+    let deserializer = MapDeserializer::<_, Error>::new(fields.into_iter());
+    let ext = AtomExtension::deserialize(deserializer).unwrap();
+    assert!(ext.categories().is_empty());
+    assert!(ext.authors().is_empty());
+}
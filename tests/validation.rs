@@ -0,0 +1,302 @@
+#![cfg(feature = "validation")]
+
+extern crate rss;
+
+use rss::extension::dublincore::DublinCoreExtension;
+use rss::validation::{Validate, ValidationError, ValidationOptions};
+use rss::{Cloud, Enclosure, Item};
+
+fn cloud(port: &str) -> Cloud {
+    let mut cloud = Cloud::default();
+    cloud.set_domain("http://example.com/");
+    cloud.set_port(port);
+    cloud.set_path("/rpc");
+    cloud.set_register_procedure("notify");
+    cloud.set_protocol("xml-rpc");
+    cloud
+}
+
+#[test]
+fn validate_cloud_port() {
+    assert!(cloud("80").validate().is_ok());
+    assert!(cloud("65535").validate().is_ok());
+    assert!(cloud("0").validate().is_err());
+    assert!(cloud("70000").validate().is_err());
+    assert!(cloud("http").validate().is_err());
+}
+
+#[test]
+fn validate_cloud_path() {
+    let mut cloud = cloud("80");
+    cloud.set_path("rpc");
+    assert!(matches!(
+        cloud.validate(),
+        Err(ValidationError::Validation(ref message)) if message.contains("rpc")
+    ));
+    cloud.set_path("/RPC2");
+    assert!(cloud.validate().is_ok());
+}
+
+fn enclosure(length: &str) -> Enclosure {
+    let mut enclosure = Enclosure::default();
+    enclosure.set_url("http://example.com/episode.mp3");
+    enclosure.set_mime_type("audio/mpeg");
+    enclosure.set_length(length);
+    enclosure
+}
+
+#[test]
+fn validate_enclosure_length() {
+    assert!(enclosure("1000").validate().is_ok());
+    assert!(enclosure("0").validate().is_ok());
+    assert!(enclosure("-1").validate().is_err());
+    assert!(enclosure("unknown").validate().is_err());
+}
+
+#[test]
+fn validate_item_pub_date_named_zone() {
+    let mut item = Item::default();
+    item.set_title("Title".to_string());
+    item.set_pub_date("Sun, 01 Jan 2017 12:00:00 UTC".to_string());
+    assert!(item.validate().is_ok());
+    item.set_pub_date("Sun, 01 Jan 2017 12:00:00 EST".to_string());
+    assert!(item.validate().is_ok());
+    item.set_pub_date("Sun, 01 Jan 2017 12:00:00 XYZ".to_string());
+    assert!(item.validate().is_err());
+}
+
+#[test]
+fn validate_item_title_or_description() {
+    let mut item = Item::default();
+    match item.validate() {
+        Err(ValidationError::Validation(message)) => {
+            assert_eq!(message, "item has neither title nor description")
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    item.set_description("Description".to_string());
+    assert!(item.validate().is_ok());
+
+    let mut item = Item::default();
+    item.set_content("<p>Content</p>".to_string());
+    assert!(item.validate().is_ok());
+
+    let mut item = Item::default();
+    let mut dublin_core = DublinCoreExtension::default();
+    dublin_core.set_descriptions(vec!["Description".to_string()]);
+    item.set_dublin_core_ext(dublin_core);
+    assert!(item.validate().is_ok());
+}
+
+#[test]
+fn validate_allowed_url_schemes() {
+    let options = ValidationOptions {
+        allowed_url_schemes: ["http", "https"].iter().map(|s| s.to_string()).collect(),
+    };
+
+    let mut enclosure = Enclosure::default();
+    enclosure.set_url("javascript:alert(1)");
+    enclosure.set_length("0");
+    enclosure.set_mime_type("audio/mpeg");
+
+    let mut item = Item::default();
+    item.set_title("Title".to_string());
+    item.set_enclosure(enclosure.clone());
+    assert!(item.validate().is_ok());
+    match item.validate_with(&options) {
+        Err(ValidationError::Validation(message)) => {
+            assert_eq!(message, "URL scheme is not allowed: javascript")
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    enclosure.set_url("HTTPS://example.com/audio.mp3");
+    item.set_enclosure(enclosure);
+    assert!(item.validate_with(&options).is_ok());
+
+    item.set_link("file:///etc/passwd".to_string());
+    assert!(item.validate_with(&options).is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn validate_par_collects_item_errors() {
+    let mut items = Vec::new();
+    for i in 0..100 {
+        let mut item = Item::default();
+        item.set_title(format!("Item {}", i));
+        if i % 10 == 0 {
+            item.set_link(format!("not a url {}", i));
+        }
+        items.push(item);
+    }
+
+    let mut channel = rss::Channel::default();
+    channel.set_link("http://example.com");
+    channel.set_title("Title");
+    channel.set_description("Description");
+    channel.set_items(items);
+
+    let errors = channel.validate_par().unwrap_err();
+    assert_eq!(errors.len(), 10);
+    assert!(channel.validate().is_err());
+
+    for item in channel.items_mut() {
+        item.set_link(None);
+    }
+    assert!(channel.validate_par().is_ok());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn validate_par_with_options() {
+    let mut items = Vec::new();
+    for i in 0..10 {
+        let mut item = Item::default();
+        item.set_title(format!("Item {}", i));
+        item.set_link(format!("ftp://example.com/{}", i));
+        items.push(item);
+    }
+
+    let mut channel = rss::Channel::default();
+    channel.set_link("http://example.com");
+    channel.set_title("Title");
+    channel.set_description("Description");
+    channel.set_items(items);
+    assert!(channel.validate_par().is_ok());
+
+    let options = ValidationOptions {
+        allowed_url_schemes: ["http".to_string()].into_iter().collect(),
+    };
+    assert!(channel.validate_with(&options).is_err());
+    assert_eq!(channel.validate_par_with(&options).unwrap_err().len(), 10);
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn validate_atom_links() {
+    use rss::extension::atom::{AtomExtension, Link};
+
+    let link = |rel: &str, href: &str| Link {
+        rel: rel.to_string(),
+        href: href.to_string(),
+        ..Default::default()
+    };
+    let mut channel = rss::Channel::default();
+    channel.set_link("http://example.com");
+    channel.set_title("Title");
+    channel.set_description("Description");
+
+    let mut atom = AtomExtension::default();
+    atom.set_links(vec![
+        link("self", "http://example.com/feed.xml"),
+        link(
+            "http://example.com/rels/custom",
+            "http://example.com/custom",
+        ),
+    ]);
+    channel.set_atom_ext(atom.clone());
+    assert!(channel.validate().is_ok());
+
+    atom.set_links(vec![link("self", "not a url")]);
+    channel.set_atom_ext(atom.clone());
+    assert!(matches!(
+        channel.validate(),
+        Err(ValidationError::UrlParsing(_))
+    ));
+
+    // relations are not checked against a registry
+    atom.set_links(vec![
+        link("preload", "http://example.com/feed.xml"),
+        link("copyright", "http://example.com/copyright"),
+    ]);
+    let mut item = Item::default();
+    item.set_title("Title".to_string());
+    item.set_atom_ext(atom.clone());
+    assert!(item.validate().is_ok());
+
+    atom.set_links(vec![link(" ", "http://example.com/feed.xml")]);
+    item.set_atom_ext(atom);
+    match item.validate() {
+        Err(ValidationError::Validation(message)) => {
+            assert_eq!(message, "Link relation is empty")
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn validate_channel_new_feed_url() {
+    use rss::extension::itunes::ITunesChannelExtension;
+    use rss::Channel;
+
+    let mut itunes = ITunesChannelExtension::default();
+    itunes.set_new_feed_url("http://example.com/new-feed.xml".to_string());
+    let mut channel = Channel::default();
+    channel.set_link("http://example.com/");
+    channel.set_title("Title");
+    channel.set_description("Description");
+    channel.set_itunes_ext(itunes.clone());
+    assert!(channel.validate().is_ok());
+
+    itunes.set_new_feed_url("not a url".to_string());
+    channel.set_itunes_ext(itunes);
+    assert!(matches!(
+        channel.validate(),
+        Err(ValidationError::UrlParsing(_))
+    ));
+}
+
+#[test]
+fn validate_image_url_whitespace() {
+    use rss::Channel;
+
+    let input = r#"
+        <rss version="2.0">
+            <channel>
+                <title>Title</title>
+                <link>http://example.com/</link>
+                <description>Description</description>
+                <image>
+                    <url> http://example.com/image.png
+                    </url>
+                    <title> Title </title>
+                    <link>
+                        http://example.com/
+                    </link>
+                </image>
+            </channel>
+        </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let image = channel.image().unwrap();
+    assert_eq!(image.url(), "http://example.com/image.png");
+    assert_eq!(image.link(), "http://example.com/");
+    assert_eq!(image.title(), "Title");
+    assert!(channel.validate().is_ok());
+}
+
+#[test]
+fn validate_channel_required_fields() {
+    let mut channel = rss::Channel::default();
+    channel.set_title("Title");
+    channel.set_link("http://example.com/");
+    channel.set_description("Description");
+    assert!(channel.validate().is_ok());
+
+    channel.set_title(" ");
+    assert!(matches!(
+        channel.validate(),
+        Err(ValidationError::Validation(ref message)) if message == "Channel title is empty"
+    ));
+
+    channel.set_title("Title");
+    channel.set_description("");
+    assert!(matches!(
+        channel.validate(),
+        Err(ValidationError::Validation(ref message)) if message == "Channel description is empty"
+    ));
+    #[cfg(feature = "rayon")]
+    assert_eq!(channel.validate_par().unwrap_err().len(), 1);
+}
@@ -1,9 +1,12 @@
 extern crate rss;
 
 use rss::{
-    extension, extension::itunes::ITunesChannelExtensionBuilder, CategoryBuilder, Channel,
-    ChannelBuilder, CloudBuilder, EnclosureBuilder, GuidBuilder, ImageBuilder, Item, ItemBuilder,
-    SourceBuilder, TextInputBuilder,
+    extension,
+    extension::itunes::ITunesChannelExtensionBuilder,
+    extension::media::{MediaContentBuilder, MediaExtensionBuilder, Medium},
+    CategoryBuilder, Channel, ChannelBuilder, CloudBuilder, EnclosureBuilder, Escape, GuidBuilder,
+    ImageBuilder, Indent, Item, ItemBuilder, ItemOrder, NamespacePlacement, SourceBuilder,
+    TextInputBuilder, WriteOptions,
 };
 use std::collections::BTreeMap;
 
@@ -278,9 +281,7 @@ fn test_escape() {
     );
 
     channel.extensions.insert("ext".to_owned(), extension_tag);
-    channel
-        .namespaces
-        .insert("ext".to_owned(), "http://example.com/ext".to_owned());
+    channel.register_namespace("ext", "http://example.com/ext");
 
     let xml = channel.to_string();
 
@@ -365,13 +366,15 @@ fn test_write_link() {
 fn test_atom_write_channel() {
     let channel = Channel {
         title: "Channel title".into(),
-        atom_ext: Some(rss::extension::atom::AtomExtension {
-            links: vec![rss::extension::atom::Link {
-                rel: "self".into(),
-                href: "http://example.com/feed".into(),
-                ..Default::default()
-            }],
-        }),
+        atom_ext: Some(
+            rss::extension::atom::AtomExtensionBuilder::default()
+                .link(rss::extension::atom::Link {
+                    rel: "self".into(),
+                    href: "http://example.com/feed".into(),
+                    ..Default::default()
+                })
+                .build(),
+        ),
         ..Default::default()
     };
 
@@ -397,13 +400,15 @@ fn test_atom_write_item() {
         title: "Channel title".into(),
         items: vec![Item {
             link: Some("http://example.com/post1".into()),
-            atom_ext: Some(rss::extension::atom::AtomExtension {
-                links: vec![rss::extension::atom::Link {
-                    rel: "related".into(),
-                    href: "http://example.com/post1".into(),
-                    ..Default::default()
-                }],
-            }),
+            atom_ext: Some(
+                rss::extension::atom::AtomExtensionBuilder::default()
+                    .link(rss::extension::atom::Link {
+                        rel: "related".into(),
+                        href: "http://example.com/post1".into(),
+                        ..Default::default()
+                    })
+                    .build(),
+            ),
             ..Default::default()
         }],
         ..Default::default()
@@ -426,3 +431,994 @@ fn test_atom_write_item() {
 </rss>"#
     );
 }
+
+#[test]
+fn test_extension_children_sorted() {
+    let child = |name: &str, value: &str| {
+        extension::ExtensionBuilder::default()
+            .name(name)
+            .value(value.to_owned())
+            .build()
+    };
+    let ext = extension::ExtensionBuilder::default()
+        .name("ext:parent")
+        .attr(("z".to_owned(), "1".to_owned()))
+        .attr(("a".to_owned(), "2".to_owned()))
+        .child((
+            "second".to_owned(),
+            vec![child("ext:second", "1"), child("ext:second", "2")],
+        ))
+        .child(("first".to_owned(), vec![child("ext:first", "3")]))
+        .build();
+
+    let mut channel = ChannelBuilder::default().build();
+    channel
+        .namespaces
+        .insert("ext".to_owned(), "http://example.com/ext".to_owned());
+    channel.extensions.insert(
+        "ext".to_owned(),
+        BTreeMap::from([("parent".to_owned(), vec![ext])]),
+    );
+
+    let xml = channel.to_string();
+    assert!(xml.contains(
+        "<ext:parent a=\"2\" z=\"1\"><ext:first>3</ext:first>\
+         <ext:second>1</ext:second><ext:second>2</ext:second></ext:parent>"
+    ));
+}
+
+#[test]
+fn test_namespace_placement_element() {
+    let channel = ChannelBuilder::default()
+        .title("Title")
+        .itunes_ext(
+            ITunesChannelExtensionBuilder::default()
+                .author("Author".to_owned())
+                .build(),
+        )
+        .item(
+            ItemBuilder::default()
+                .content("Lorem ipsum dolor sit amet".to_owned())
+                .dublin_core_ext(
+                    extension::dublincore::DublinCoreExtensionBuilder::default()
+                        .creators(vec!["Creator 1".to_owned(), "Creator 2".to_owned()])
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+
+    let options = WriteOptions {
+        namespace_placement: NamespacePlacement::Element,
+        ..Default::default()
+    };
+    let xml = String::from_utf8(channel.write_to_with(Vec::new(), &options).unwrap()).unwrap();
+
+    assert!(xml.contains("<rss version=\"2.0\"><channel>"));
+    assert!(xml.contains(
+        "<itunes:author xmlns:itunes=\"http://www.itunes.com/dtds/podcast-1.0.dtd\">Author"
+    ));
+    assert!(xml.contains("<content:encoded xmlns:content="));
+    assert_eq!(xml.matches("xmlns:dc=").count(), 2);
+
+    let parsed = xml.parse::<Channel>().expect("failed to parse xml");
+    assert_eq!(channel, parsed);
+}
+
+#[test]
+fn test_media_content() {
+    let content = MediaContentBuilder::default()
+        .url("http://example.com/audio.mp3".to_owned())
+        .mime_type("audio/mpeg".to_owned())
+        .medium(Medium::Audio)
+        .file_size(2048)
+        .duration(std::time::Duration::from_secs(90))
+        .bitrate(128)
+        .extra(("isDefault".to_owned(), "true".to_owned()))
+        .build();
+    let item = ItemBuilder::default()
+        .media_ext(MediaExtensionBuilder::default().content(content).build())
+        .build();
+    let channel = ChannelBuilder::default().item(item).build();
+
+    let output = channel.to_string();
+    assert!(output.contains("xmlns:media=\"http://search.yahoo.com/mrss/\""));
+    assert!(output.contains(
+        "<media:content bitrate=\"128\" duration=\"90\" fileSize=\"2048\" isDefault=\"true\" medium=\"audio\" type=\"audio/mpeg\" url=\"http://example.com/audio.mp3\"></media:content>"
+    ));
+    let parsed = output.parse::<Channel>().unwrap();
+    assert_eq!(channel.items(), parsed.items());
+}
+
+#[test]
+fn test_media_content_prefix_taken() {
+    let item = ItemBuilder::default()
+        .media_ext(
+            MediaExtensionBuilder::default()
+                .content(
+                    MediaContentBuilder::default()
+                        .url("http://example.com/a.mp3".to_owned())
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+    let mut channel = ChannelBuilder::default().item(item).build();
+    channel.namespaces.insert(
+        "media".to_string(),
+        "http://example.com/not-media".to_string(),
+    );
+
+    let output = channel.to_string();
+    assert!(output.contains(r#"<rss version="2.0" xmlns:media="http://example.com/not-media">"#));
+    assert!(output.contains(r#"<item xmlns:media="http://search.yahoo.com/mrss/">"#));
+    let parsed = output.parse::<Channel>().unwrap();
+    assert_eq!(
+        channel.items()[0].media_ext(),
+        parsed.items()[0].media_ext()
+    );
+
+    let mut item = Item::default();
+    item.set_namespaces([(
+        "media".to_string(),
+        "http://example.com/not-media".to_string(),
+    )]);
+    item.set_media_ext(channel.items()[0].media_ext().cloned());
+    let output = Channel::from(item).to_string();
+    assert!(output.contains(r#"xmlns:media1="http://search.yahoo.com/mrss/""#));
+    assert!(output.contains(r#"<media1:content url="http://example.com/a.mp3">"#));
+}
+
+#[test]
+fn test_media_content_other_prefix() {
+    let input = r#"
+    <rss version="2.0" xmlns:m="http://search.yahoo.com/mrss/">
+        <channel>
+            <item>
+                <m:content url="http://example.com/video.mp4">
+                    <m:title>Video</m:title>
+                    <m:thumbnail url="http://example.com/thumb.jpg"/>
+                </m:content>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+
+    let output = channel.to_string();
+    assert!(output.contains(r#"xmlns:m="http://search.yahoo.com/mrss/""#));
+    assert!(output.contains(r#"<m:content url="http://example.com/video.mp4">"#));
+    assert!(output.contains("<m:title>Video</m:title>"));
+    assert!(output.contains(r#"<m:thumbnail url="http://example.com/thumb.jpg">"#));
+    assert!(!output.contains("xmlns:media"));
+
+    let parsed = output.parse::<Channel>().unwrap();
+    assert_eq!(
+        channel.items()[0].media_ext(),
+        parsed.items()[0].media_ext()
+    );
+    assert_eq!(
+        parsed.items()[0].media_ext().unwrap().contents()[0].children()["title"][0].value(),
+        Some("Video")
+    );
+}
+
+#[test]
+fn test_pretty_write_indent_tabs() {
+    let item = ItemBuilder::default().title("Title".to_owned()).build();
+    let channel = ChannelBuilder::default().item(item).build();
+
+    let buf = channel
+        .pretty_write_to_indent(Vec::new(), Indent::Tabs(1))
+        .unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    assert!(output.contains("\n\t<channel>"));
+    assert!(output.contains("\n\t\t<item>"));
+    assert!(output.contains("\n\t\t\t<title>Title</title>"));
+    assert!(!output.contains("\t\t\t\t"));
+
+    let spaces = channel
+        .pretty_write_to_indent(Vec::new(), Indent::Spaces(4))
+        .unwrap();
+    assert_eq!(
+        spaces,
+        channel.pretty_write_to(Vec::new(), b' ', 4).unwrap()
+    );
+}
+
+#[test]
+fn test_category_domain_escape() {
+    let input = r#"
+    <rss version="2.0">
+        <channel>
+            <category domain="http://x?a=1&amp;b=2&amp;c=%26">Q&amp;A</category>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let category = &channel.categories()[0];
+    assert_eq!(category.name(), "Q&A");
+    assert_eq!(category.domain(), Some("http://x?a=1&b=2&c=%26"));
+
+    let output = channel.to_string();
+    assert!(
+        output.contains(r#"<category domain="http://x?a=1&amp;b=2&amp;c=%26">Q&amp;A</category>"#)
+    );
+    test_write!(channel);
+}
+
+#[test]
+fn test_write_item_order_reverse() {
+    let channel = ChannelBuilder::default()
+        .items(vec![
+            ItemBuilder::default().title("First".to_string()).build(),
+            ItemBuilder::default().title("Second".to_string()).build(),
+        ])
+        .build();
+
+    let options = WriteOptions {
+        item_order: ItemOrder::Reverse,
+        ..Default::default()
+    };
+    let xml = String::from_utf8(channel.write_to_with(Vec::new(), &options).unwrap()).unwrap();
+    let first = xml.find("<title>First</title>").unwrap();
+    let second = xml.find("<title>Second</title>").unwrap();
+    assert!(second < first);
+    assert_eq!(channel.items()[0].title(), Some("First"));
+
+    let xml = channel.to_string();
+    assert!(xml.find("<title>First</title>") < xml.find("<title>Second</title>"));
+}
+
+#[test]
+fn test_write_cdata_fields() {
+    let channel = ChannelBuilder::default()
+        .items(vec![ItemBuilder::default()
+            .description("<p>A & B</p>".to_string())
+            .content("<p>Content</p>".to_string())
+            .build()])
+        .build();
+
+    let xml = channel.to_string();
+    assert!(xml.contains("<description><![CDATA[<p>A & B</p>]]></description>"));
+    assert!(xml.contains("<content:encoded><![CDATA[<p>Content</p>]]></content:encoded>"));
+
+    let mut options = WriteOptions::default();
+    options.cdata_fields.remove("description");
+    let xml = String::from_utf8(channel.write_to_with(Vec::new(), &options).unwrap()).unwrap();
+    assert!(xml.contains("<description>&lt;p&gt;A &amp; B&lt;/p&gt;</description>"));
+    assert!(xml.contains("<content:encoded><![CDATA[<p>Content</p>]]></content:encoded>"));
+}
+
+#[test]
+fn test_write_content_without_cdata() {
+    let html = r#"<p class="intro">Tom & Jerry &lt;3 <a href="/?a=1&amp;b=2">link</a></p>"#;
+    let channel = ChannelBuilder::default()
+        .items(vec![ItemBuilder::default()
+            .content(html.to_string())
+            .build()])
+        .build();
+
+    let mut options = WriteOptions::default();
+    options.cdata_fields.clear();
+    let xml = String::from_utf8(channel.write_to_with(Vec::new(), &options).unwrap()).unwrap();
+    assert!(!xml.contains("CDATA"));
+    assert!(xml.contains(
+        "<content:encoded>&lt;p class=&quot;intro&quot;&gt;Tom &amp; Jerry &amp;lt;3 \
+         &lt;a href=&quot;/?a=1&amp;amp;b=2&quot;&gt;link&lt;/a&gt;&lt;/p&gt;</content:encoded>"
+    ));
+
+    let parsed = xml.parse::<Channel>().unwrap();
+    assert_eq!(parsed.items()[0].content(), Some(html));
+}
+
+#[cfg(feature = "validation")]
+#[test]
+fn test_write_date_format() {
+    let channel = ChannelBuilder::default()
+        .pub_date("2017-01-01T12:00:00+02:00".to_string())
+        .last_build_date("not a date".to_string())
+        .items(vec![ItemBuilder::default()
+            .pub_date("2017-01-01T12:00:00Z".to_string())
+            .build()])
+        .build();
+
+    let xml = channel.to_string();
+    assert!(xml.contains("<pubDate>2017-01-01T12:00:00+02:00</pubDate>"));
+
+    let options = WriteOptions {
+        date_format: Some(rss::DateFormat::Rfc2822),
+        ..Default::default()
+    };
+    let xml = String::from_utf8(channel.write_to_with(Vec::new(), &options).unwrap()).unwrap();
+    assert!(xml.contains("<pubDate>Sun, 1 Jan 2017 12:00:00 +0200</pubDate>"));
+    assert!(xml.contains("<pubDate>Sun, 1 Jan 2017 12:00:00 +0000</pubDate>"));
+    assert!(xml.contains("<lastBuildDate>not a date</lastBuildDate>"));
+    assert_eq!(channel.pub_date(), Some("2017-01-01T12:00:00+02:00"));
+}
+
+#[cfg(not(feature = "atom"))]
+#[test]
+fn test_atom_write_item_without_feature() {
+    let mut link = extension::Extension::default();
+    link.set_name("atom:link");
+    link.attrs.insert("rel".to_string(), "self".to_string());
+    link.attrs
+        .insert("href".to_string(), "http://example.com/1".to_string());
+    let mut atom = BTreeMap::new();
+    atom.insert("link".to_string(), vec![link]);
+    let mut extensions = BTreeMap::new();
+    extensions.insert("atom".to_string(), atom);
+
+    let channel = ChannelBuilder::default()
+        .items(vec![ItemBuilder::default().extensions(extensions).build()])
+        .build();
+    let xml = channel.to_string();
+    assert!(xml.contains(r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">"#));
+    assert!(xml.contains(r#"<atom:link href="http://example.com/1" rel="self"></atom:link>"#));
+
+    let parsed = xml.parse::<Channel>().unwrap();
+    assert_eq!(parsed.items(), channel.items());
+}
+
+#[cfg(feature = "atom")]
+#[test]
+fn test_atom_author_and_category_roundtrip() {
+    let input = r#"
+    <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+        <channel>
+            <item>
+                <title>Title</title>
+                <atom:category term="rust" scheme="http://example.com/tags" label="Rust"/>
+                <atom:author>
+                    <atom:name>Jane Doe</atom:name>
+                    <atom:email>jane@example.com</atom:email>
+                    <atom:uri>http://example.com/jane</atom:uri>
+                </atom:author>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    let ext = channel.items()[0].atom_ext().unwrap();
+    assert_eq!(ext.categories()[0].term(), "rust");
+    assert_eq!(ext.categories()[0].label(), Some("Rust"));
+    assert_eq!(ext.authors()[0].name(), "Jane Doe");
+    assert_eq!(ext.authors()[0].email(), Some("jane@example.com"));
+    assert_eq!(ext.authors()[0].uri(), Some("http://example.com/jane"));
+
+    let xml = channel.to_string();
+    assert!(xml.contains(
+        "<atom:author><atom:name>Jane Doe</atom:name><atom:email>jane@example.com</atom:email>"
+    ));
+    let parsed = xml.parse::<Channel>().unwrap();
+    assert_eq!(parsed.items(), channel.items());
+}
+
+#[test]
+fn test_xml_lang_roundtrip() {
+    let input = r#"
+    <rss version="2.0">
+        <channel xml:lang="en">
+            <title>Title</title>
+            <item xml:lang="fr">
+                <title>Bonjour</title>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(channel.xml_lang(), Some("en"));
+    assert_eq!(channel.language(), Some("en"));
+    assert_eq!(channel.items()[0].xml_lang(), Some("fr"));
+
+    let xml = channel.to_string();
+    assert!(xml.contains(r#"<channel xml:lang="en">"#));
+    assert!(xml.contains(r#"<item xml:lang="fr">"#));
+    assert!(!xml.contains("<language>"));
+    assert_eq!(xml.parse::<Channel>().unwrap(), channel);
+
+    let options = WriteOptions {
+        namespace_placement: NamespacePlacement::Element,
+        ..Default::default()
+    };
+    let xml = String::from_utf8(channel.write_to_with(Vec::new(), &options).unwrap()).unwrap();
+    assert!(xml.contains(r#"<item xml:lang="fr">"#));
+    assert!(!xml.contains("xmlns:xml"));
+}
+
+#[test]
+fn test_to_bytes() {
+    let item = ItemBuilder::default().title("Title".to_owned()).build();
+    let channel = ChannelBuilder::default().item(item).build();
+
+    assert_eq!(channel.to_bytes(), channel.write_to(Vec::new()).unwrap());
+    assert_eq!(channel.to_bytes(), channel.to_string().into_bytes());
+    assert_eq!(
+        channel.to_pretty_bytes(Indent::Tabs(1)),
+        channel
+            .pretty_write_to_indent(Vec::new(), Indent::Tabs(1))
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_write_item_namespace_rebound() {
+    let input = r#"
+    <rss version="2.0" xmlns:x="http://example.com/x">
+        <channel>
+            <x:meta>Channel</x:meta>
+            <item xmlns:x="http://example.com/other">
+                <x:meta>Item</x:meta>
+            </item>
+            <item>
+                <x:meta>Second item</x:meta>
+            </item>
+        </channel>
+    </rss>
+    "#;
+    let channel = input.parse::<Channel>().unwrap();
+    assert_eq!(
+        channel.items()[0].namespaces().get("x").map(String::as_str),
+        Some("http://example.com/other")
+    );
+
+    for placement in [NamespacePlacement::Root, NamespacePlacement::Element] {
+        let options = WriteOptions {
+            namespace_placement: placement,
+            ..Default::default()
+        };
+        let output = channel.write_to_with(Vec::new(), &options).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(r#"<item xmlns:x="http://example.com/other">"#));
+
+        let parsed = output.parse::<Channel>().unwrap();
+        assert_eq!(
+            parsed.items()[0].namespaces(),
+            channel.items()[0].namespaces()
+        );
+        assert_eq!(
+            parsed.items()[1].namespaces(),
+            channel.items()[1].namespaces()
+        );
+    }
+}
+
+#[test]
+fn test_write_deterministic() {
+    fn build() -> Channel {
+        let input = r#"
+        <rss version="2.0" xmlns:ext="http://example.com/ext" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd" xmlns:media="http://search.yahoo.com/mrss/" xmlns:a="http://example.com/a" xmlns:z="http://example.com/z">
+            <channel>
+                <title>Title</title>
+                <ext:meta zeta="1" alpha="2" mid="3"><ext:child b="1" a="2"/></ext:meta>
+                <itunes:author>Author</itunes:author>
+                <item>
+                    <z:thing/>
+                    <a:thing/>
+                    <dc:creator>Creator</dc:creator>
+                    <media:content url="http://example.com/a.mp3" medium="audio" zz="1" aa="2"/>
+                    <enclosure url="http://example.com/a.mp3" length="1" type="audio/mpeg"/>
+                </item>
+            </channel>
+        </rss>
+        "#;
+        input.parse::<Channel>().unwrap()
+    }
+
+    let channel = build();
+    let first = channel.to_bytes();
+    assert_eq!(first, channel.to_bytes());
+    assert_eq!(first, build().to_bytes());
+
+    let xml = String::from_utf8(first).unwrap();
+    assert!(xml.contains(concat!(
+        r#"<rss version="2.0" xmlns:a="http://example.com/a" "#,
+        r#"xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:ext="http://example.com/ext" "#,
+        r#"xmlns:itunes="http://www.itunes.com/dtds/podcast-1.0.dtd" "#,
+        r#"xmlns:media="http://search.yahoo.com/mrss/" xmlns:z="http://example.com/z">"#
+    )));
+    assert!(xml.contains(r#"<ext:meta alpha="2" mid="3" zeta="1">"#));
+
+    let options = WriteOptions {
+        namespace_placement: NamespacePlacement::Element,
+        ..Default::default()
+    };
+    assert_eq!(
+        channel.write_to_with(Vec::new(), &options).unwrap(),
+        build().write_to_with(Vec::new(), &options).unwrap()
+    );
+}
+
+#[test]
+fn test_guid_extra_attributes_round_trip() {
+    let input =
+        r#"<rss version="2.0"><channel><item><guid foo="bar">abc</guid></item></channel></rss>"#;
+    let channel = input.parse::<Channel>().unwrap();
+    let guid = channel.items()[0].guid().unwrap();
+    assert_eq!(guid.value(), "abc");
+    assert_eq!(guid.extras().get("foo").map(String::as_str), Some("bar"));
+
+    let output = channel.to_string();
+    assert!(output.contains(r#"<guid isPermaLink="true" foo="bar">abc</guid>"#));
+    test_write!(channel);
+}
+
+#[test]
+fn test_guid_explicit_permalink() {
+    let guid = |value: &str, permalink: bool| {
+        let channel = ChannelBuilder::default()
+            .items(vec![ItemBuilder::default()
+                .guid(
+                    GuidBuilder::default()
+                        .value(value)
+                        .permalink(permalink)
+                        .build(),
+                )
+                .build()])
+            .build();
+        let xml = channel.to_string();
+        let start = xml.find("<guid").unwrap();
+        let end = xml.find("</guid>").unwrap() + "</guid>".len();
+        xml[start..end].to_string()
+    };
+
+    assert_eq!(
+        guid("http://example.com/1", true),
+        r#"<guid isPermaLink="true">http://example.com/1</guid>"#
+    );
+    assert_eq!(
+        guid("tag:example.com,2017:1", false),
+        r#"<guid isPermaLink="false">tag:example.com,2017:1</guid>"#
+    );
+    assert!(rss::Guid::default().is_permalink());
+}
+
+#[test]
+fn test_escape_channel_text_fields() {
+    let special = "a & b <c> \"d\" 'e' ]]> &amp;";
+    let channel = ChannelBuilder::default()
+        .title(special)
+        .link(special)
+        .description(special)
+        .language(special.to_string())
+        .xml_lang(special.to_string())
+        .copyright(special.to_string())
+        .managing_editor(special.to_string())
+        .webmaster(special.to_string())
+        .pub_date(special.to_string())
+        .last_build_date(special.to_string())
+        .generator(special.to_string())
+        .docs(special.to_string())
+        .rating(special.to_string())
+        .ttl(special.to_string())
+        .build();
+
+    let output = channel.to_string();
+    assert!(!output.contains("<c>"));
+    assert!(output.contains("<copyright>a &amp; b &lt;c&gt;"));
+    assert!(output.contains("<generator>a &amp; b &lt;c&gt;"));
+    assert!(output.contains("<managingEditor>a &amp; b &lt;c&gt;"));
+    assert!(output.contains("&amp;amp;"));
+
+    let parsed = output.parse::<Channel>().expect("failed to parse xml");
+    assert_eq!(channel, parsed);
+    assert_eq!(parsed.copyright(), Some(special));
+    assert_eq!(parsed.generator(), Some(special));
+    assert_eq!(parsed.managing_editor(), Some(special));
+}
+
+#[test]
+fn test_channel_from_items() {
+    let item = ItemBuilder::default().title("One".to_string()).build();
+    let channel = Channel::from(item.clone());
+    assert_eq!(channel.items().len(), 1);
+    assert!(channel
+        .to_string()
+        .contains("<item><title>One</title></item>"));
+
+    let channel = (0..3).map(|_| item.clone()).collect::<Channel>();
+    assert_eq!(channel.items().len(), 3);
+    assert_eq!(channel.title(), "");
+}
+
+#[test]
+fn test_cdata_end_sequence_round_trip() {
+    let description = "]]>a]]>]]> <b>c</b> ]]";
+    let item = ItemBuilder::default()
+        .description(description.to_string())
+        .build();
+    let channel = Channel::from(item);
+
+    let output = channel.to_string();
+    assert!(output.contains(concat!(
+        "<description><![CDATA[]]]]><![CDATA[>a]]]]><![CDATA[>]]]]><![CDATA[>",
+        " <b>c</b> ]]]]></description>"
+    )));
+
+    let parsed = output.parse::<Channel>().unwrap();
+    assert_eq!(parsed.items()[0].description(), Some(description));
+}
+
+#[test]
+fn test_write_options_escape() {
+    let channel = ChannelBuilder::default()
+        .title("\"Fish\" & 'chips' <b>")
+        .build();
+
+    let output = String::from_utf8(channel.to_bytes()).unwrap();
+    assert!(output.contains("<title>&quot;Fish&quot; &amp; &apos;chips&apos; &lt;b&gt;</title>"));
+
+    let options = WriteOptions {
+        escape: Escape::Partial,
+        ..Default::default()
+    };
+    let output = channel.write_to_with(Vec::new(), &options).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("<title>\"Fish\" &amp; 'chips' &lt;b&gt;</title>"));
+    assert_eq!(output.parse::<Channel>().unwrap(), channel);
+}
+
+#[test]
+fn test_write_options_declaration() {
+    let channel = Channel::default();
+    let output = channel
+        .write_to_with(Vec::new(), &WriteOptions::default())
+        .unwrap();
+    assert!(output.starts_with(b"<?xml version=\"1.0\" encoding=\"utf-8\"?><rss"));
+
+    let options = WriteOptions {
+        write_declaration: false,
+        ..Default::default()
+    };
+    let output = channel.write_to_with(Vec::new(), &options).unwrap();
+    assert!(output.starts_with(b"<rss version=\"2.0\">"));
+}
+
+#[test]
+fn test_write_options_indent() {
+    let channel = ChannelBuilder::default().title("Title").build();
+    let options = WriteOptions {
+        indent: Some(Indent::Spaces(3)),
+        ..Default::default()
+    };
+    let output = channel.write_to_with(Vec::new(), &options).unwrap();
+    assert_eq!(output, channel.to_pretty_bytes(Indent::Spaces(3)));
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("\n   <channel>\n      <title>Title</title>"));
+}
+
+#[test]
+fn test_channel_with_items() {
+    let input = include_str!("data/itunes.xml");
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    let mut expected = channel.clone();
+
+    let items = vec![ItemBuilder::default().title("New".to_string()).build()];
+    let channel = channel.with_items(items.clone());
+    assert_eq!(channel.items(), &items[..]);
+    expected.set_items(items);
+    assert_eq!(channel, expected);
+
+    let mut channel = channel;
+    channel.clear_items();
+    assert!(channel.items().is_empty());
+    assert_eq!(channel.title(), expected.title());
+    assert_eq!(channel.itunes_ext(), expected.itunes_ext());
+    test_write!(channel);
+}
+
+#[test]
+fn test_write_options_item_element_name() {
+    let item = ItemBuilder::default().title("Title".to_string()).build();
+    let channel = Channel::from(item);
+    let options = WriteOptions {
+        item_element_name: "entry",
+        ..Default::default()
+    };
+    let output = channel.write_to_with(Vec::new(), &options).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.contains("<entry><title>Title</title></entry>"));
+    assert!(!output.contains("<item>"));
+}
+
+#[test]
+fn test_processing_instructions_round_trip() {
+    let input = concat!(
+        r#"<?xml version="1.0" encoding="utf-8"?>"#,
+        "\n",
+        r#"<?xml-stylesheet href="/style.xsl" type="text/xsl"?>"#,
+        "\n",
+        r#"<rss version="2.0"><channel><title>Title</title></channel></rss>"#
+    );
+
+    let channel = input.parse::<Channel>().unwrap();
+    assert!(channel.processing_instructions().is_empty());
+    assert!(!channel.to_string().contains("xml-stylesheet"));
+
+    let options = rss::ReadOptions {
+        preserve_processing_instructions: true,
+        ..Default::default()
+    };
+    let channel = Channel::read_from_with_options(input.as_bytes(), &options).unwrap();
+    assert_eq!(
+        channel.processing_instructions(),
+        [r#"xml-stylesheet href="/style.xsl" type="text/xsl""#]
+    );
+
+    let output = channel.to_string();
+    assert!(output.starts_with(concat!(
+        r#"<?xml version="1.0" encoding="utf-8"?>"#,
+        r#"<?xml-stylesheet href="/style.xsl" type="text/xsl"?><rss"#
+    )));
+    let parsed = Channel::read_from_with_options(output.as_bytes(), &options).unwrap();
+    assert_eq!(parsed, channel);
+
+    // an instruction that would end early cannot be written
+    let channel = ChannelBuilder::default()
+        .processing_instruction("xml-stylesheet ?><rss/><?x".to_string())
+        .build();
+    assert!(matches!(
+        channel.write_to(Vec::new()),
+        Err(rss::Error::InvalidValue {
+            field: "processing_instructions",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_enclosure_from_file_meta() {
+    let enclosure =
+        EnclosureBuilder::from_file_meta("http://example.com/episode.mp3", 123_456, "audio/mpeg")
+            .build();
+    assert_eq!(enclosure.url(), "http://example.com/episode.mp3");
+    assert_eq!(enclosure.length(), "123456");
+    assert_eq!(enclosure.mime_type(), "audio/mpeg");
+
+    let inferred = EnclosureBuilder::default()
+        .length("123456")
+        .url_with_inferred_type("http://example.com/episode.mp3?token=1")
+        .build();
+    assert_eq!(inferred.mime_type(), "audio/mpeg");
+
+    let unknown = EnclosureBuilder::default()
+        .mime_type("application/octet-stream")
+        .url_with_inferred_type("http://example.com/episode.bin")
+        .build();
+    assert_eq!(unknown.mime_type(), "application/octet-stream");
+
+    let channel = Channel::from(ItemBuilder::default().enclosure(enclosure).build());
+    assert!(channel.to_string().contains(
+        r#"<enclosure url="http://example.com/episode.mp3" length="123456" type="audio/mpeg"/>"#
+    ));
+}
+
+#[test]
+fn test_fill_enclosure_metadata() {
+    use rss::{EnclosureMetadata, HeadClient};
+    use std::cell::RefCell;
+
+    struct MockClient {
+        response: Result<EnclosureMetadata, &'static str>,
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl HeadClient for MockClient {
+        type Error = &'static str;
+
+        fn head(&self, url: &str) -> Result<EnclosureMetadata, Self::Error> {
+            self.requests.borrow_mut().push(url.to_string());
+            self.response.clone()
+        }
+    }
+
+    let client = MockClient {
+        response: Ok(EnclosureMetadata {
+            content_length: Some(12_345_678),
+            content_type: Some("audio/x-m4a; charset=binary".to_string()),
+        }),
+        requests: RefCell::new(Vec::new()),
+    };
+    let mut item = Item::default();
+    item.fill_enclosure_metadata("http://example.com/episode.mp3?id=1", &client)
+        .unwrap();
+    assert_eq!(
+        *client.requests.borrow(),
+        ["http://example.com/episode.mp3?id=1"]
+    );
+    let enclosure = item.enclosure().unwrap();
+    assert_eq!(enclosure.url(), "http://example.com/episode.mp3?id=1");
+    assert_eq!(enclosure.length(), "12345678");
+    assert_eq!(enclosure.mime_type(), "audio/x-m4a");
+
+    let client = MockClient {
+        response: Ok(EnclosureMetadata {
+            content_length: None,
+            content_type: None,
+        }),
+        requests: RefCell::new(Vec::new()),
+    };
+    item.fill_enclosure_metadata("http://example.com/episode.ogg", &client)
+        .unwrap();
+    let enclosure = item.enclosure().unwrap();
+    assert_eq!(enclosure.length(), "0");
+    assert_eq!(enclosure.mime_type(), "audio/ogg");
+
+    let client = MockClient {
+        response: Err("timed out"),
+        requests: RefCell::new(Vec::new()),
+    };
+    assert_eq!(
+        item.fill_enclosure_metadata("http://example.com/episode", &client),
+        Err("timed out")
+    );
+    let enclosure = item.enclosure().unwrap();
+    assert_eq!(enclosure.url(), "http://example.com/episode");
+    assert_eq!(enclosure.length(), "0");
+    assert_eq!(enclosure.mime_type(), "");
+}
+
+#[test]
+fn test_builder_optional_setters_clear() {
+    macro_rules! assert_clears {
+        ($builder:ty, $setter:ident, $value:expr) => {{
+            let mut builder = <$builder>::default();
+            let value = $value;
+            builder.$setter(Some(value.clone()));
+            assert_eq!(builder.build().$setter, Some(value));
+            builder.$setter(None);
+            assert_eq!(builder.build().$setter, None);
+        }};
+    }
+
+    assert_clears!(ChannelBuilder, language, "en-US".to_string());
+    assert_clears!(ChannelBuilder, xml_lang, "en-US".to_string());
+    assert_clears!(ChannelBuilder, copyright, "Copyright".to_string());
+    assert_clears!(
+        ChannelBuilder,
+        managing_editor,
+        "editor@example.com".to_string()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        webmaster,
+        "webmaster@example.com".to_string()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        pub_date,
+        "Sun, 01 Jan 2017 12:00:00 GMT".to_string()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        last_build_date,
+        "Sun, 01 Jan 2017 12:00:00 GMT".to_string()
+    );
+    assert_clears!(ChannelBuilder, generator, "Generator".to_string());
+    assert_clears!(ChannelBuilder, docs, "http://example.com/docs".to_string());
+    assert_clears!(
+        ChannelBuilder,
+        cloud,
+        CloudBuilder::default().domain("example.com").build()
+    );
+    assert_clears!(ChannelBuilder, rating, "PICS".to_string());
+    assert_clears!(ChannelBuilder, ttl, "60".to_string());
+    assert_clears!(
+        ChannelBuilder,
+        image,
+        ImageBuilder::default()
+            .url("http://example.com/image.png")
+            .build()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        text_input,
+        TextInputBuilder::default().name("q").build()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        itunes_ext,
+        ITunesChannelExtensionBuilder::default()
+            .author("Author".to_string())
+            .build()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        dublin_core_ext,
+        extension::dublincore::DublinCoreExtension::default()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        syndication_ext,
+        extension::syndication::SyndicationExtension::default()
+    );
+    #[cfg(feature = "atom")]
+    assert_clears!(
+        ChannelBuilder,
+        atom_ext,
+        extension::atom::AtomExtension::default()
+    );
+    assert_clears!(
+        ChannelBuilder,
+        xml_declaration,
+        rss::XmlDeclaration::default()
+    );
+
+    assert_clears!(ItemBuilder, title, "Title".to_string());
+    assert_clears!(ItemBuilder, link, "http://example.com/".to_string());
+    assert_clears!(ItemBuilder, description, "Description".to_string());
+    assert_clears!(ItemBuilder, author, "author@example.com".to_string());
+    assert_clears!(
+        ItemBuilder,
+        comments,
+        "http://example.com/comments".to_string()
+    );
+    assert_clears!(
+        ItemBuilder,
+        enclosure,
+        EnclosureBuilder::default()
+            .url("http://example.com/a.mp3")
+            .build()
+    );
+    assert_clears!(ItemBuilder, guid, GuidBuilder::default().value("1").build());
+    assert_clears!(
+        ItemBuilder,
+        pub_date,
+        "Sun, 01 Jan 2017 12:00:00 GMT".to_string()
+    );
+    assert_clears!(
+        ItemBuilder,
+        source,
+        SourceBuilder::default().url("http://example.com/").build()
+    );
+    assert_clears!(ItemBuilder, content, "Content".to_string());
+    assert_clears!(ItemBuilder, xml_lang, "en-US".to_string());
+    assert_clears!(
+        ItemBuilder,
+        itunes_ext,
+        extension::itunes::ITunesItemExtension::default()
+    );
+    assert_clears!(
+        ItemBuilder,
+        dublin_core_ext,
+        extension::dublincore::DublinCoreExtension::default()
+    );
+    let content = MediaContentBuilder::default().build();
+    let media = MediaExtensionBuilder::default().content(content).build();
+    let item = ItemBuilder::default()
+        .media_ext(media)
+        .media_ext(None)
+        .build();
+    assert!(item.media_ext().is_none());
+    assert!(item.extensions().is_empty());
+    #[cfg(feature = "atom")]
+    assert_clears!(
+        ItemBuilder,
+        atom_ext,
+        extension::atom::AtomExtension::default()
+    );
+    assert_clears!(ItemBuilder, raw_xml, "<item/>".to_string());
+}
+
+#[test]
+fn test_channel_builder_or_default() {
+    let channel = ChannelBuilder::default()
+        .title("Title")
+        .title_or_default(None::<&str>)
+        .link_or_default(Some("http://example.com/"))
+        .description_or_default(Some("Description".to_string()))
+        .build();
+    assert_eq!(channel.title(), "");
+    assert_eq!(channel.link(), "http://example.com/");
+    assert_eq!(channel.description(), "Description");
+}
+
+#[test]
+fn write_rss091_ignores_source_format() {
+    let input = r#"<rss version="0.91"><channel><title>Title</title></channel></rss>"#;
+    let channel = input.parse::<Channel>().expect("failed to parse xml");
+    let parsed = channel.to_string().parse::<Channel>().unwrap();
+    assert_ne!(channel.feed_type(), parsed.feed_type());
+    assert_eq!(channel, parsed);
+}